@@ -0,0 +1,92 @@
+use crate::{Neuron, NeuronError, NeuronParams};
+
+// Step-by-step construction of a `Neuron`, with optional validation of the axon coordinate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NeuronBuilder {
+    pos: (usize, usize, usize),  // neuron coordinate
+    axon: (usize, usize, usize), // axon coordinate ( defaults to the neuron coordinate )
+    nt: u32,                     // neuron type ( 0 = Contact , 1 = Sensory , 2 = Motor )
+    nrt: u32,                    // neurotransmitter type ( 0 = Inhibitory , 1 = Excitatory )
+    params: NeuronParams,        // per-neuron tunable parameters
+    strict: bool,                // whether an axon coinciding with the neuron is an error ( false = warning )
+}
+
+impl NeuronBuilder {
+    // Starts building an excitatory contact neuron at a coordinate, with its axon at the same coordinate.
+    // Parameters:
+    // - `x`: Neuron x-coordinate.
+    // - `y`: Neuron y-coordinate.
+    // - `z`: Neuron z-coordinate.
+    pub fn new(x: usize, y: usize, z: usize) -> Self {
+        NeuronBuilder {
+            pos: (x, y, z),
+            axon: (x, y, z),
+            nt: 0,
+            nrt: 1,
+            params: NeuronParams::default(),
+            strict: false,
+        }
+    }
+
+    // Parameters:
+    // - `ax`: Axon x-coordinate.
+    // - `ay`: Axon y-coordinate.
+    // - `az`: Axon z-coordinate.
+    pub fn axon(mut self, ax: usize, ay: usize, az: usize) -> Self {
+        self.axon = (ax, ay, az);
+        self
+    }
+
+    // Parameters:
+    // - `nt`: Neuron type (0 = Contact, 1 = Sensory, 2 = Motor).
+    pub fn neuron_type(mut self, nt: u32) -> Self {
+        self.nt = nt;
+        self
+    }
+
+    // Parameters:
+    // - `nrt`: Neurotransmitter type (0 = Inhibitory, 1 = Excitatory).
+    pub fn neurotransmitter(mut self, nrt: u32) -> Self {
+        self.nrt = nrt;
+        self
+    }
+
+    // Parameters:
+    // - `params`: The per-neuron tunable parameters.
+    pub fn params(mut self, params: NeuronParams) -> Self {
+        self.params = params;
+        self
+    }
+
+    // Parameters:
+    // - `strict`: True to reject an axon coordinate equal to the neuron coordinate; false to only log a warning.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    // Builds the neuron.
+    // Returns: The neuron, `NeuronError::InvalidNeuronType` or `NeuronError::InvalidNeurotransmitterType` for an unknown type,
+    // or `NeuronError::AxonAtSoma` in strict mode if the axon coordinate equals the neuron coordinate.
+    pub fn build(self) -> Result<Neuron, NeuronError> {
+        if self.nt > 2 {
+            return Err(NeuronError::InvalidNeuronType(self.nt));
+        }
+        if self.nrt > 1 {
+            return Err(NeuronError::InvalidNeurotransmitterType(self.nrt));
+        }
+        if self.axon == self.pos && self.strict {
+            return Err(NeuronError::AxonAtSoma(self.pos));
+        }
+        #[cfg(feature = "log")]
+        if self.axon == self.pos {
+            log::warn!("({}, {}, {}) -> axon coordinate coincides with the neuron", self.pos.0, self.pos.1, self.pos.2);
+        }
+
+        let (x, y, z) = self.pos;
+        let (ax, ay, az) = self.axon;
+        let mut neuron = Neuron::new(x, y, z, ax, ay, az, self.nt, self.nrt);
+        neuron.params = self.params;
+        Ok(neuron)
+    }
+}
@@ -0,0 +1,68 @@
+use serde::{Serialize, Deserialize};
+
+// A point on the integer lattice of neuron and axon coordinates.
+// Methods taking a coordinate accept either a `Coord3` or an `(x, y, z)` tuple. Connection sets and serialized data keep
+// tuples so the file format is unchanged. Coordinate arithmetic lives here so differences never underflow.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Coord3 {
+    pub x: usize, // x-coordinate
+    pub y: usize, // y-coordinate
+    pub z: usize, // z-coordinate
+}
+
+impl Coord3 {
+    // Creates a coordinate.
+    // Parameters:
+    // - `x`: The x-coordinate.
+    // - `y`: The y-coordinate.
+    // - `z`: The z-coordinate.
+    pub const fn new(x: usize, y: usize, z: usize) -> Self {
+        Coord3 { x, y, z }
+    }
+
+    // Parameters:
+    // - `other`: The other coordinate.
+    // Returns: The per-axis distance to `other`, which cannot underflow.
+    pub fn abs_diff(self, other: Coord3) -> Coord3 {
+        Coord3::new(self.x.abs_diff(other.x), self.y.abs_diff(other.y), self.z.abs_diff(other.z))
+    }
+
+    // Parameters:
+    // - `other`: The other coordinate.
+    // Returns: The Euclidean distance to `other`.
+    #[cfg(feature = "std")]
+    pub fn distance_to(self, other: Coord3) -> f64 {
+        let d = self.abs_diff(other);
+        let (dx, dy, dz) = (d.x as f64, d.y as f64, d.z as f64);
+        (dx * dx + dy * dy + dz * dz).sqrt()
+    }
+
+    // Shifts the coordinate by a signed offset along each axis.
+    // Parameters:
+    // - `dx`: The x offset.
+    // - `dy`: The y offset.
+    // - `dz`: The z offset.
+    // Returns: The shifted coordinate, or `None` if it would leave the lattice.
+    pub fn offset(self, dx: isize, dy: isize, dz: isize) -> Option<Coord3> {
+        Some(Coord3::new(self.x.checked_add_signed(dx)?, self.y.checked_add_signed(dy)?, self.z.checked_add_signed(dz)?))
+    }
+}
+
+// Parameters:
+// - `coord`: A `Coord3` or an `(x, y, z)` tuple.
+// Returns: The coordinate as an `(x, y, z)` tuple.
+pub(crate) fn into_tuple(coord: impl Into<Coord3>) -> (usize, usize, usize) {
+    coord.into().into()
+}
+
+impl From<(usize, usize, usize)> for Coord3 {
+    fn from((x, y, z): (usize, usize, usize)) -> Self {
+        Coord3::new(x, y, z)
+    }
+}
+
+impl From<Coord3> for (usize, usize, usize) {
+    fn from(coord: Coord3) -> Self {
+        (coord.x, coord.y, coord.z)
+    }
+}
@@ -0,0 +1,118 @@
+use core::error::Error;
+use core::fmt;
+use core::time::Duration;
+use crate::NeuronId;
+
+// Errors reported by fallible neuron and network operations.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NeuronError {
+    DimensionMismatch { expected: usize, found: usize }, // input length does not match the neuron count
+    DuplicatePosition((usize, usize, usize)),            // two neurons share a coordinate
+    SelfConnection(NeuronId),                            // a neuron was connected to itself
+    WeightSignMismatch { from: NeuronId, to: NeuronId }, // weight sign disagrees with the neurotransmitter type
+    OutOfRange { field: &'static str, value: f64 },      // a neuron field lies outside its valid range
+    AxonAtSoma((usize, usize, usize)),                   // the axon coordinate equals the neuron coordinate
+    FanOutExceeded { pos: (usize, usize, usize), limit: usize }, // a neuron already has its maximum number of axonal connections
+    InvalidNeuronType(u32),                              // the neuron type is not 0, 1, or 2
+    InvalidNeurotransmitterType(u32),                    // the neurotransmitter type is not 0 or 1
+}
+
+impl fmt::Display for NeuronError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NeuronError::DimensionMismatch { expected, found } => write!(f, "dimension mismatch: expected {}, found {}", expected, found),
+            NeuronError::DuplicatePosition(pos) => write!(f, "{:?} : position is already occupied", pos),
+            NeuronError::SelfConnection(id) => write!(f, "{} : a neuron cannot connect to itself", id),
+            NeuronError::WeightSignMismatch { from, to } => write!(f, "{} -> {} : weight sign does not match the neurotransmitter type", from, to),
+            NeuronError::OutOfRange { field, value } => write!(f, "{} : value {} is out of range", field, value),
+            NeuronError::AxonAtSoma(pos) => write!(f, "{:?} : axon coordinate coincides with the neuron", pos),
+            NeuronError::FanOutExceeded { pos, limit } => write!(f, "{:?} : fan-out limit of {} axonal connections reached", pos, limit),
+            NeuronError::InvalidNeuronType(nt) => write!(f, "{} : nt must be 0, 1, or 2", nt),
+            NeuronError::InvalidNeurotransmitterType(nrt) => write!(f, "{} : nrt must be 0 or 1", nrt),
+        }
+    }
+}
+
+impl Error for NeuronError {}
+
+// Errors reported by time-bounded signal transmission.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TransmitError {
+    DelayTimeout { delay: Duration, timeout: Duration }, // the signal delay exceeded the timeout and the signal was dropped
+}
+
+impl fmt::Display for TransmitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TransmitError::DelayTimeout { delay, timeout } => write!(f, "signal delay of {:?} exceeded the timeout of {:?}", delay, timeout),
+        }
+    }
+}
+
+impl Error for TransmitError {}
+
+// Error reported when an operation requires an acyclic network but the axonal connections form a cycle.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CycleError {
+    pub node: NeuronId, // a neuron that lies on the cycle
+}
+
+impl fmt::Display for CycleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} : neuron lies on a cycle", self.node)
+    }
+}
+
+impl Error for CycleError {}
+
+// Errors reported when saving or loading a persisted network, so callers can match on the failure cause.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub enum PersistenceError {
+    Io(std::io::Error),          // the file could not be read or written
+    Serde(serde_json::Error),    // the file is not valid JSON for the expected layout
+    UnsupportedVersion(u32),     // the file was written by a newer format version
+    ChecksumMismatch,            // the neuron data does not match its recorded checksum
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for PersistenceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PersistenceError::Io(err) => write!(f, "io error: {}", err),
+            PersistenceError::Serde(err) => write!(f, "malformed data: {}", err),
+            PersistenceError::UnsupportedVersion(version) => write!(f, "{} : unsupported format version", version),
+            PersistenceError::ChecksumMismatch => write!(f, "checksum mismatch : the neuron data is corrupted"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for PersistenceError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PersistenceError::Io(err) => Some(err),
+            PersistenceError::Serde(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for PersistenceError {
+    fn from(err: std::io::Error) -> Self {
+        PersistenceError::Io(err)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<serde_json::Error> for PersistenceError {
+    // Failures of the underlying reader or writer surface as `Io`, so only malformed data is reported as `Serde`.
+    fn from(err: serde_json::Error) -> Self {
+        if err.is_io() {
+            PersistenceError::Io(err.into())
+        } else {
+            PersistenceError::Serde(err)
+        }
+    }
+}
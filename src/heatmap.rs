@@ -0,0 +1,77 @@
+#[cfg(feature = "image")]
+use std::io;
+#[cfg(feature = "image")]
+use std::path::Path;
+use serde::{Serialize, Deserialize};
+use crate::{Network, Neuron};
+
+// Pixel size of one grid cell in rendered heatmaps.
+#[cfg(feature = "image")]
+const CELL_PIXELS: u32 = 8;
+
+// Background color of grid cells without any neuron.
+#[cfg(feature = "image")]
+const BACKGROUND: [u8; 3] = [0, 0, 0];
+
+// The axis a heatmap projects along; the other two axes span the grid.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    X, // grid columns = y, rows = z
+    Y, // grid columns = x, rows = z
+    Z, // grid columns = x, rows = y
+}
+
+impl Network {
+    // Projects the network's firing rates onto a 2D grid.
+    // Parameters:
+    // - `axis`: The axis to project along.
+    // Returns: The grid as rows of cells, indexed by coordinate from 0 to the largest occupied coordinate.
+    // Each cell holds the highest firing rate among the neurons projected onto it, or None if the cell is empty.
+    pub fn activity_heatmap(&self, axis: Axis) -> Vec<Vec<Option<f64>>> {
+        let project = |neuron: &Neuron| match axis {
+            Axis::X => (neuron.y, neuron.z),
+            Axis::Y => (neuron.x, neuron.z),
+            Axis::Z => (neuron.x, neuron.y),
+        };
+        let Some(columns) = self.neurons().iter().map(|neuron| project(neuron).0 + 1).max() else {
+            return Vec::new();
+        };
+        let rows = self.neurons().iter().map(|neuron| project(neuron).1 + 1).max().unwrap();
+
+        let mut grid = vec![vec![None; columns]; rows];
+        for neuron in self.neurons() {
+            let (column, row) = project(neuron);
+            let cell: &mut Option<f64> = &mut grid[row][column];
+            *cell = Some(cell.map_or(neuron.fr, |fr| fr.max(neuron.fr)));
+        }
+        grid
+    }
+
+    // Renders the projected firing rates as a PNG image.
+    // Firing rates map to a gradient from blue (0) to red (`Neuron::MAX_FIRING_RATE`); empty cells are black.
+    // Parameters:
+    // - `axis`: The axis to project along.
+    // - `path`: The file to write.
+    #[cfg(feature = "image")]
+    pub fn render_activity_png<P: AsRef<Path>>(&self, axis: Axis, path: P) -> io::Result<()> {
+        let grid = self.activity_heatmap(axis);
+        let rows = grid.len().max(1) as u32;
+        let columns = grid.first().map_or(1, |row| row.len()) as u32;
+
+        let image = image::RgbImage::from_fn(columns * CELL_PIXELS, rows * CELL_PIXELS, |px, py| {
+            let cell = grid.get((py / CELL_PIXELS) as usize).and_then(|row| row[(px / CELL_PIXELS) as usize]);
+            image::Rgb(cell.map_or(BACKGROUND, gradient))
+        });
+        image.save_with_format(path, image::ImageFormat::Png).map_err(io::Error::other)
+    }
+}
+
+// Maps a firing rate to a blue-to-red color.
+// Parameters:
+// - `fr`: The firing rate.
+// Returns: The RGB color.
+#[cfg(feature = "image")]
+fn gradient(fr: f64) -> [u8; 3] {
+    let t = (fr / Neuron::MAX_FIRING_RATE).clamp(0.0, 1.0);
+    [(255.0 * t).round() as u8, 0, (255.0 * (1.0 - t)).round() as u8]
+}
@@ -1,7 +1,133 @@
-use std::collections::HashSet;
+// Without the default `std` feature only the core neuron model is built, on top of `alloc`:
+// the async `transmit` path, networks, persistence and analysis helpers require `std`.
+#![cfg_attr(not(feature = "std"), no_std)]
+extern crate alloc;
+
+use alloc::sync::Arc;
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, vec::Vec};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(not(feature = "std"))]
+use alloc::collections::BTreeMap;
 use serde::{Serialize, Deserialize};
+#[cfg(feature = "std")]
 use tokio::time::{sleep, Duration};
+#[cfg(feature = "rand")]
+use rand::{rngs::SmallRng, Rng, RngExt, SeedableRng};
+
+mod builder;
+mod coord;
+mod error;
+#[cfg(feature = "std")]
+mod heatmap;
+#[cfg(feature = "std")]
+mod metrics;
+#[cfg(feature = "std")]
+mod network;
+mod params;
+#[cfg(feature = "std")]
+mod persistence;
+mod plasticity;
+#[cfg(feature = "std")]
+mod raster;
+#[cfg(feature = "std")]
+mod shared;
+pub use builder::NeuronBuilder;
+pub use coord::Coord3;
+pub use error::{CycleError, NeuronError, TransmitError};
+#[cfg(feature = "std")]
+pub use error::PersistenceError;
+#[cfg(feature = "std")]
+pub use heatmap::Axis;
+#[cfg(feature = "std")]
+pub use metrics::Metrics;
+#[cfg(feature = "std")]
+pub use network::{FiringSnapshot, LayerSpec, Network, WeightSnapshot};
+pub use params::NeuronParams;
+#[cfg(feature = "std")]
+pub use persistence::{ExternalPort, JsonFormat};
+pub use plasticity::{AdditiveRule, PlasticityRule, PlasticitySummary};
+#[cfg(feature = "std")]
+pub use raster::{isi_statistics, spike_cross_correlation, IsiStats, SpikeRaster};
+#[cfg(feature = "std")]
+pub use shared::SharedNetwork;
+
+// The set type holding a neuron's connection coordinates: a `HashSet` with `std`, or a `BTreeSet` without `std`
+// or with the `deterministic` feature, whose iteration yields coordinates in sorted order.
+#[cfg(all(feature = "std", not(feature = "deterministic")))]
+pub type ConnectionSet = std::collections::HashSet<(usize, usize, usize)>;
+#[cfg(any(not(feature = "std"), feature = "deterministic"))]
+pub type ConnectionSet = alloc::collections::BTreeSet<(usize, usize, usize)>;
+
+// Identifier of a neuron within a network (its index in insertion order).
+pub type NeuronId = usize;
+
+// Co-activation counts per presynaptic partner coordinate.
+#[cfg(feature = "std")]
+type CoactivationMap = HashMap<(usize, usize, usize), u64>;
+#[cfg(not(feature = "std"))]
+type CoactivationMap = BTreeMap<(usize, usize, usize), u64>;
+
+// Type of neurotransmitter released by a neuron, matching the values of `Neuron::nrt`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neurotransmitter {
+    Inhibitory = 0,
+    Excitatory = 1,
+}
+
+impl From<Neurotransmitter> for u32 {
+    fn from(nrt: Neurotransmitter) -> u32 {
+        nrt as u32
+    }
+}
+
+// Activity state of a neuron, as classified by `Neuron::firing_state`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FiringState {
+    Quiescent,     // membrane potential well below the threshold
+    NearThreshold, // membrane potential within `Neuron::NEAR_THRESHOLD_MARGIN` of the threshold
+    Firing,        // membrane potential at or above the threshold; the next `detect` fires
+    Refractory,    // fired and still in the absolute refractory period
+}
+
+// How inhibitory inputs combine with excitatory inputs arriving in the same integration window, as used by `Neuron::transmit_many`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InhibitionMode {
+    Subtractive, // inhibitory inputs are subtracted from the excitatory sum
+    Shunting,    // inhibitory inputs divide the excitatory sum, as shunting inhibition near the soma
+}
+
+// How an incoming signal is scaled by the distance to its source, as configured by `NeuronParams::distance_attenuation`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DistanceAttenuation {
+    #[default]
+    None,          // signals arrive at full strength regardless of distance
+    InverseLinear, // signals are scaled by `1 / d`
+    InverseSquare, // signals are scaled by `1 / d²`
+}
+
+// Direction of the connection between a neuron and a partner, as reported by `Neuron::connection_direction`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionDirection {
+    Unconnected, // no connection in either direction
+    Afferent,    // incoming only, through a dendritic connection
+    Efferent,    // outgoing only, through an axonal connection
+    Reciprocal,  // both incoming and outgoing
+}
+
+// A user-supplied function computing the signal emitted by `fire`.
+#[derive(Clone)]
+struct FireTransform(Arc<dyn Fn(&Neuron) -> f64 + Send + Sync>);
 
+impl core::fmt::Debug for FireTransform {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("FireTransform")
+    }
+}
+
+// Serialization compatibility: every field added after the initial release is annotated with `#[serde(default)]`
+// and takes the value `Neuron::new` would give it, so checkpoints saved by older versions still load.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Neuron {
     // ---- Neuron cell parameters ----
@@ -18,26 +144,55 @@ pub struct Neuron {
     pub tp: f64,  // threshold potential
     pub mp: f64,  // membrane potential
     pub fr: f64,  // firing rate
+    #[serde(default)]
+    pub ta: f64,  // cumulative threshold adaptation, used when `params.cumulative_threshold_adaptation` is set
+    #[serde(default)]
+    pub fire_count: u64, // number of times the neuron has fired
+    #[serde(default)]
+    exc_drive: f64, // sum of the excitatory inputs processed since the last fire
+    #[serde(default)]
+    inh_drive: f64, // sum of the inhibitory inputs processed since the last fire ( zero or negative )
+    #[serde(skip)]
+    fire_transform: Option<FireTransform>, // custom output signal function ( None = built-in formula )
+    #[serde(default)]
+    pub frozen: bool, // whether the state is clamped: inputs are ignored and firing leaves it unchanged
+    #[serde(default)]
+    pub params: NeuronParams, // per-neuron tunable parameters
 
     // ---- Synaptic plasticity related parameters ----
     pub sw: f64,  // synaptic weight
     pub sst: f64, // synaptic strength threshold
     pub pr: f64,  // plasticity rate
+    #[serde(skip, default = "Neuron::default_plasticity_rule")]
+    pub plasticity_rule: Arc<dyn PlasticityRule>, // synaptic weight learning rule
+    #[serde(default = "Neuron::default_plasticity_enabled")]
+    pub plasticity_enabled: bool, // whether transmit updates plasticity ( false = weights frozen )
 
     // ---- Dynamic parameters related to neuron activity ----
     pub arp: f64,  // absolute refractory period
     pub rrp: f64,  // relative refractory period
 
     // ---- Parameters related to synaptic connection formation ----
-    pub ac: HashSet<(usize, usize, usize)>, // axonal connections
-    pub dc: HashSet<(usize, usize, usize)>, // dendritic connections
+    pub ac: ConnectionSet, // axonal connections
+    pub dc: ConnectionSet, // dendritic connections
+    #[serde(skip)]
+    coactivations: Option<CoactivationMap>, // co-activation counts per presynaptic partner ( None = not tracked )
 
     // ---- Biological regulatory factors ----
     pub nc: f64,  // neurotransmitter concentration
+    #[serde(default = "Neuron::default_receptor_sensitivity")]
+    pub receptor_sensitivity: f64, // input gain of the receptors, reduced by firing and restored while idle
 
     // ---- Long-term adjustment and pruning ----
     pub ltp: f64, // long term potentiation factor
     pub ltd: f64, // long term depression factor
+
+    // ---- Stochastic behavior ----
+    #[serde(default)]
+    pub seed: Option<u64>, // random number generator seed ( None = seeded from entropy )
+    #[cfg(feature = "rand")]
+    #[serde(skip)]
+    rng: Option<SmallRng>, // random number generator, created lazily from `seed`
 }
 
 impl Neuron {
@@ -72,6 +227,37 @@ impl Neuron {
     pub const LTD_BOOST_FACTOR: f64 = 0.01;
     pub const LTD_DECREASE_FACTOR: f64 = 0.96;
     pub const SYNAPTIC_STRENGTH_THRESHOLD_BOOST_FACTOR: f64 = 0.01;
+    pub const NEAR_THRESHOLD_MARGIN: f64 = 5.0;
+    // Valid range of each bounded field, in the order of `bounded_fields` and `bounded_fields_mut`.
+    const FIELD_BOUNDS: [(&'static str, f64, f64); 12] = [
+        ("mp", Self::MIN_MEMBRANE_POTENTIAL, Self::MAX_MEMBRANE_POTENTIAL),
+        ("tp", Self::MIN_THRESHOLD_POTENTIAL, Self::MAX_THRESHOLD_POTENTIAL),
+        ("fr", 0.0, Self::MAX_FIRING_RATE),
+        ("sw", Self::MIN_LTD, Self::MAX_LTP),
+        ("sst", Self::MIN_LTD, Self::MAX_LTP),
+        ("pr", 0.0, Self::MAX_PLASTICITY_RATE),
+        ("ltp", 0.0, Self::MAX_LTP),
+        ("ltd", Self::MIN_LTD, 0.0),
+        ("arp", 0.0, Self::BASE_ABSOLUTE_REFRACTORY_PERIOD),
+        ("rrp", 0.0, Self::BASE_RELATIVE_REFRACTORY_PERIOD),
+        ("receptor_sensitivity", 0.0, Self::MAX_RECEPTOR_SENSITIVITY),
+        ("nc", 0.0, Self::MAX_NEUROTRANSMITTER_CONCENTRATION),
+    ];
+    pub const MAX_RECEPTOR_SENSITIVITY: f64 = 1.0;
+    pub const RECEPTOR_DESENSITIZATION_FACTOR: f64 = 0.05;
+    pub const RECEPTOR_RECOVERY_FACTOR: f64 = 0.1;
+    pub const MEMBRANE_TIME_CONSTANT: f64 = 0.0;
+    pub const MIN_SYNAPTIC_DELAY: f64 = 0.0;
+    pub const THRESHOLD_ADAPTATION_INCREMENT: f64 = 0.5;
+    pub const THRESHOLD_ADAPTATION_DECAY_FACTOR: f64 = 0.01;
+    pub const MAX_NEUROTRANSMITTER_CONCENTRATION: f64 = 2.0;
+    pub const FEATURE_COUNT: usize = 14;
+    pub const SHUNTING_INHIBITION_CONSTANT: f64 = 10.0;
+    pub const EXCITATORY_REVERSAL_POTENTIAL: f64 = 0.0;
+    pub const INHIBITORY_REVERSAL_POTENTIAL: f64 = -80.0;
+    pub const ADAPTATION_INDEX_EPSILON: f64 = 1e-3;
+    pub const STOCHASTIC_GAIN: f64 = 1.0;
+    const RATE_TO_INPUT_ITERATIONS: usize = 60;
 
     // Creates a new Neuron instance.
     // Parameters:
@@ -83,6 +269,7 @@ impl Neuron {
     // - `az`: Axon z-coordinate.
     // - `nt`: Neuron type (0 = Contact, 1 = Sensory, 2 = Motor).
     // - `nrt`: Neurotransmitter type (0 = Inhibitory, 1 = Excitatory).
+    #[allow(clippy::too_many_arguments)]
     pub fn new(x: usize, y: usize, z: usize, ax: usize, ay: usize, az: usize, nt: u32, nrt: u32) -> Self {
         if nt > 2 {
             panic!("error: {} : nt must be 0, 1, or 2", nt);
@@ -105,33 +292,252 @@ impl Neuron {
             tp: Self::MIN_THRESHOLD_POTENTIAL,
             mp: Self::RESTING_POTENTIAL,
             fr: 0.0,
+            ta: 0.0,
+            fire_count: 0,
+            exc_drive: 0.0,
+            inh_drive: 0.0,
+            fire_transform: None,
+            frozen: false,
+            params: NeuronParams::default(),
             sw: 1.0,
             sst: 0.0,
             pr: 1.0,
+            plasticity_rule: Self::default_plasticity_rule(),
+            plasticity_enabled: Self::default_plasticity_enabled(),
             arp: 0.0,
             rrp: Self::BASE_RELATIVE_REFRACTORY_PERIOD,
-            ac: HashSet::new(),
-            dc: HashSet::new(),
+            ac: ConnectionSet::new(),
+            dc: ConnectionSet::new(),
+            coactivations: None,
             nc: 1.0,
+            receptor_sensitivity: Self::default_receptor_sensitivity(),
             ltp: 0.0,
             ltd: 0.0,
+            seed: None,
+            #[cfg(feature = "rand")]
+            rng: None,
+        }
+    }
+
+    // Creates a new Neuron instance like `new`, validating the axon coordinate.
+    // Parameters:
+    // - `x`, `y`, `z`: Neuron coordinate.
+    // - `ax`, `ay`, `az`: Axon coordinate.
+    // - `nt`: Neuron type (0 = Contact, 1 = Sensory, 2 = Motor).
+    // - `nrt`: Neurotransmitter type (0 = Inhibitory, 1 = Excitatory).
+    // - `strict`: True to reject an axon coordinate equal to the neuron coordinate; false to only log a warning.
+    // Returns: The neuron, `NeuronError::InvalidNeuronType` or `NeuronError::InvalidNeurotransmitterType` for an unknown type,
+    // or `NeuronError::AxonAtSoma` in strict mode if the axon coordinate equals the neuron coordinate.
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_new(x: usize, y: usize, z: usize, ax: usize, ay: usize, az: usize, nt: u32, nrt: u32, strict: bool) -> Result<Self, NeuronError> {
+        NeuronBuilder::new(x, y, z).axon(ax, ay, az).neuron_type(nt).neurotransmitter(nrt).strict(strict).build()
+    }
+
+    // Creates a builder pre-populated with this neuron's construction parameters (coordinate, axon, types and `params`),
+    // so a copy with a few changes can be rebuilt with validation. Building it unchanged reproduces an equivalent freshly created neuron;
+    // the activity and learning state and the connections are not carried over.
+    // Returns: The pre-populated builder, in lenient mode.
+    pub fn to_builder(&self) -> NeuronBuilder {
+        NeuronBuilder::new(self.x, self.y, self.z)
+            .axon(self.ax, self.ay, self.az)
+            .neuron_type(self.nt)
+            .neurotransmitter(self.nrt)
+            .params(self.params)
+    }
+
+    // Resets the activity state (potentials, firing rate and refractory periods) to its initial values.
+    // Learned synaptic parameters and connections are kept, and the random number generator is reseeded from `seed`.
+    pub fn reset(&mut self) {
+        self.ap = 0.0;
+        self.tp = Self::MIN_THRESHOLD_POTENTIAL;
+        self.mp = Self::RESTING_POTENTIAL;
+        self.fr = 0.0;
+        self.ta = 0.0;
+        self.exc_drive = 0.0;
+        self.inh_drive = 0.0;
+        self.arp = 0.0;
+        self.rrp = Self::BASE_RELATIVE_REFRACTORY_PERIOD;
+        self.receptor_sensitivity = Self::default_receptor_sensitivity();
+        #[cfg(feature = "rand")]
+        {
+            self.rng = None;
+        }
+    }
+
+    // Interpolates between two neuron states, for morphing experiments or averaging ensemble members.
+    // Every scalar state field becomes `a + t * (b - a)`; the position, axon, types, parameters and connections are taken from `a`.
+    // Parameters:
+    // - `a`: The neuron at `t = 0`.
+    // - `b`: The neuron at `t = 1`.
+    // - `t`: The interpolation factor, clamped to [0, 1].
+    // Returns: The blended neuron.
+    pub fn blend(a: &Neuron, b: &Neuron, t: f64) -> Neuron {
+        let t = t.clamp(0.0, 1.0);
+        let mut blended = a.clone();
+        for (value, target) in blended.scalar_fields_mut().into_iter().zip(b.scalar_fields()) {
+            *value += t * (target - *value);
+        }
+        blended
+    }
+
+    // Applies a genetic mutation to the tunable parameters (`sw`, `sst`, `pr`, `ltp`, `ltd`, `nc`) for evolutionary optimization.
+    // Each field is perturbed with probability `rate` by Gaussian noise of standard deviation `magnitude`,
+    // then every field is clamped back into its valid range, so the result passes `validate`.
+    // Parameters:
+    // - `rate`: The probability of mutating each field, clamped to [0, 1].
+    // - `magnitude`: The standard deviation of the perturbation.
+    // - `rng`: The random number generator drawing the mutations.
+    #[cfg(feature = "rand")]
+    pub fn mutate(&mut self, rate: f64, magnitude: f64, rng: &mut impl Rng) {
+        let rate = rate.clamp(0.0, 1.0);
+        for value in self.tunable_fields_mut() {
+            if rng.random_bool(rate) {
+                *value += magnitude * standard_normal(rng);
+            }
+        }
+        self.clamp_to_params();
+    }
+
+    // Produces an offspring of two neurons by uniform crossover, for genetic algorithms over populations of neurons.
+    // A randomly chosen parent provides the position, axon, types, activity state, parameters and connections;
+    // each tunable parameter (`sw`, `sst`, `pr`, `ltp`, `ltd`, `nc`) is then inherited from either parent with equal probability.
+    // Parameters:
+    // - `a`: The first parent.
+    // - `b`: The second parent.
+    // - `rng`: The random number generator drawing the inheritance.
+    // Returns: The offspring.
+    #[cfg(feature = "rand")]
+    pub fn crossover(a: &Neuron, b: &Neuron, rng: &mut impl Rng) -> Neuron {
+        let (base, other) = if rng.random_bool(0.5) { (a, b) } else { (b, a) };
+        let mut offspring = base.clone();
+        for (value, alternative) in offspring.tunable_fields_mut().into_iter().zip(other.tunable_fields()) {
+            if rng.random_bool(0.5) {
+                *value = alternative;
+            }
+        }
+        offspring
+    }
+
+    // Randomizes the activity and plasticity state, so a network can start warm instead of silent and skip long warmup transients.
+    // The membrane potential, firing rate, and plasticity fields (`sw`, `sst`, `pr`, `ltp`, `ltd`) are drawn uniformly
+    // from their valid ranges, so the result passes `validate`; the accumulated potential is set to match the membrane potential.
+    // Parameters:
+    // - `rng`: The random number generator drawing the state.
+    #[cfg(feature = "rand")]
+    pub fn with_random_state(&mut self, rng: &mut impl Rng) {
+        const RANDOMIZED: [&str; 7] = ["mp", "fr", "sw", "sst", "pr", "ltp", "ltd"];
+        for ((field, min, max), value) in Self::FIELD_BOUNDS.iter().zip(self.bounded_fields_mut()) {
+            if RANDOMIZED.contains(field) {
+                *value = rng.random_range(*min..=*max);
+            }
+        }
+        self.ap = self.mp - Self::RESTING_POTENTIAL;
+    }
+
+    // Clamps every bounded field back into the range the update functions maintain,
+    // making a neuron consistent again after its public fields were edited by hand.
+    pub fn clamp_to_params(&mut self) {
+        for ((_, min, max), value) in Self::FIELD_BOUNDS.iter().zip(self.bounded_fields_mut()) {
+            *value = value.clamp(*min, *max);
+        }
+    }
+
+    // Checks that every bounded field lies within the range the update functions maintain.
+    // Returns: `Ok(())` if the neuron is consistent, or `NeuronError::OutOfRange` naming the first offending field.
+    pub fn validate(&self) -> Result<(), NeuronError> {
+        for ((field, min, max), value) in Self::FIELD_BOUNDS.iter().zip(self.bounded_fields()) {
+            if !(*min..=*max).contains(&value) {
+                return Err(NeuronError::OutOfRange { field, value });
+            }
+        }
+        Ok(())
+    }
+
+    // Returns: True if the neuron is a contact neuron.
+    pub fn is_contact(&self) -> bool {
+        self.nt == 0
+    }
+
+    // Returns: True if the neuron is a sensory neuron.
+    pub fn is_sensory(&self) -> bool {
+        self.nt == 1
+    }
+
+    // Returns: True if the neuron is a motor neuron.
+    pub fn is_motor(&self) -> bool {
+        self.nt == 2
+    }
+
+    // Returns: True if the neuron releases an inhibitory neurotransmitter.
+    pub fn is_inhibitory(&self) -> bool {
+        self.nrt == 0
+    }
+
+    // Returns: True if the neuron releases an excitatory neurotransmitter.
+    pub fn is_excitatory(&self) -> bool {
+        self.nrt == 1
+    }
+
+    // Returns: The axonal connection coordinates, sorted lexicographically.
+    pub fn axonal_connections(&self) -> Vec<(usize, usize, usize)> {
+        let mut connections: Vec<_> = self.ac.iter().copied().collect();
+        connections.sort_unstable();
+        connections
+    }
+
+    // Returns: The dendritic connection coordinates, sorted lexicographically.
+    pub fn dendritic_connections(&self) -> Vec<(usize, usize, usize)> {
+        let mut connections: Vec<_> = self.dc.iter().copied().collect();
+        connections.sort_unstable();
+        connections
+    }
+
+    // Parameters:
+    // - `partner_pos`: The coordinate of the partner neuron.
+    // Returns: Whether the partner is connected through `dc` (afferent), `ac` (efferent), both (reciprocal), or not at all.
+    pub fn connection_direction(&self, partner_pos: impl Into<Coord3>) -> ConnectionDirection {
+        let partner_pos = coord::into_tuple(partner_pos);
+        match (self.dc.contains(&partner_pos), self.ac.contains(&partner_pos)) {
+            (false, false) => ConnectionDirection::Unconnected,
+            (true, false) => ConnectionDirection::Afferent,
+            (false, true) => ConnectionDirection::Efferent,
+            (true, true) => ConnectionDirection::Reciprocal,
         }
     }
 
     // Establishes the axonal connection with a specified dendritic neuron.
     // Parameters:
     // - `neuron`: A mutable reference to the neuron to connect to.
-    pub fn establish_axonal_connection(&mut self, neuron: &mut Neuron) {
+    // Returns: `NeuronError::FanOutExceeded` if this neuron already has `params.max_fan_out` other axonal connections.
+    pub fn establish_axonal_connection(&mut self, neuron: &mut Neuron) -> Result<(), NeuronError> {
+        self.check_fan_out((neuron.x, neuron.y, neuron.z))?;
         self.ac.insert((neuron.x, neuron.y, neuron.z));
         neuron.dc.insert((self.x, self.y, self.z));
+        Ok(())
     }
     
     // Establishes the dendritic connection with a specified axonal neuron.
     // Parameters:
     // - `neuron`: A mutable reference to the neuron to connect to.
-    pub fn establish_dendritic_connection(&mut self, neuron: &mut Neuron) {
+    // Returns: `NeuronError::FanOutExceeded` if the axonal neuron already has `params.max_fan_out` other axonal connections.
+    pub fn establish_dendritic_connection(&mut self, neuron: &mut Neuron) -> Result<(), NeuronError> {
+        neuron.check_fan_out((self.x, self.y, self.z))?;
         self.dc.insert((neuron.x, neuron.y, neuron.z));
         neuron.ac.insert((self.x, self.y, self.z));
+        Ok(())
+    }
+
+    // Checks whether a new axonal connection fits within the fan-out limit.
+    // Parameters:
+    // - `target`: The coordinate of the dendritic neuron; an existing connection to it always fits.
+    // Returns: `NeuronError::FanOutExceeded` if the connection is new and the limit is already reached.
+    fn check_fan_out(&self, target: (usize, usize, usize)) -> Result<(), NeuronError> {
+        match self.params.max_fan_out {
+            Some(limit) if self.ac.len() >= limit && !self.ac.contains(&target) => {
+                Err(NeuronError::FanOutExceeded { pos: (self.x, self.y, self.z), limit })
+            }
+            _ => Ok(()),
+        }
     }
     
     // Terminates the axonal connection with a specified dendritic neuron.
@@ -150,14 +556,26 @@ impl Neuron {
         neuron.ac.remove(&(self.x, self.y, self.z));
     }
 
+    // Merges another neuron's axonal and dendritic connections into this neuron's connection sets.
+    // Only this neuron is changed; partners that referenced the other neuron's coordinate are not updated.
+    // Parameters:
+    // - `other`: A reference to the neuron whose connections are merged.
+    pub fn merge_connections_from(&mut self, other: &Neuron) {
+        self.ac.extend(other.ac.iter().copied());
+        self.dc.extend(other.dc.iter().copied());
+    }
+
     // Prunes axonal connections based on the dendritic neuron.
     // Parameters:
     // - `neuron`: A mutable reference to the neuron used for pruning.
     pub fn prune_axonal_connection(&mut self, neuron: &mut Neuron) {
         if self.sw <= self.sst && self.sw < neuron.sw {
             self.terminate_axonal_connection(neuron);
+            #[cfg(feature = "log")]
+            log::info!("({}, {}, {}) -> pruned axonal connection to ({}, {}, {})", self.x, self.y, self.z, neuron.x, neuron.y, neuron.z);
         } else if self.sw >= neuron.sw {
-            self.establish_axonal_connection(neuron);
+            // A neuron at its fan-out limit keeps only its existing connections
+            let _ = self.establish_axonal_connection(neuron);
         }
     }
 
@@ -167,119 +585,719 @@ impl Neuron {
     pub fn prune_dendritic_connection(&mut self, neuron: &mut Neuron) {
         if self.sw <= self.sst && neuron.sw < self.sw {
             self.terminate_dendritic_connection(neuron);
+            #[cfg(feature = "log")]
+            log::info!("({}, {}, {}) -> pruned dendritic connection to ({}, {}, {})", self.x, self.y, self.z, neuron.x, neuron.y, neuron.z);
         } else if neuron.sw >= self.sw {
-            self.establish_dendritic_connection(neuron);
+            // An axonal neuron at its fan-out limit keeps only its existing connections
+            let _ = self.establish_dendritic_connection(neuron);
+        }
+    }
+
+    // Returns: The coordinate of the neuron.
+    pub fn position(&self) -> Coord3 {
+        Coord3::new(self.x, self.y, self.z)
+    }
+
+    // Returns: The coordinate of the neuron's axon.
+    pub fn axon_position(&self) -> Coord3 {
+        Coord3::new(self.ax, self.ay, self.az)
+    }
+
+    // Returns: The distance to the threshold (`tp - mp`); positive while below the threshold, zero or negative at or above it.
+    pub fn threshold_distance(&self) -> f64 {
+        self.tp - self.mp
+    }
+
+    // Separates the drive behind the accumulated potential into its excitatory and inhibitory parts, revealing
+    // whether the neuron is excitation- or inhibition-dominated. Signals blocked by the refractory period are not counted.
+    // Returns: The sums of the positive and of the negative inputs processed since the last fire, as `(excitatory, inhibitory)`.
+    pub fn drive_balance(&self) -> (f64, f64) {
+        (self.exc_drive, self.inh_drive)
+    }
+
+    // Measures spike-frequency adaptation as how far the threshold has risen above its baseline.
+    // Returns: `(tp - MIN_THRESHOLD_POTENTIAL) / (MAX_THRESHOLD_POTENTIAL - MIN_THRESHOLD_POTENTIAL)`, clamped to [0, 1];
+    // 0 at baseline and 1 when fully adapted.
+    pub fn adaptation_index(&self) -> f64 {
+        ((self.tp - Self::MIN_THRESHOLD_POTENTIAL) / (Self::MAX_THRESHOLD_POTENTIAL - Self::MIN_THRESHOLD_POTENTIAL)).clamp(0.0, 1.0)
+    }
+
+    // Returns: True if the adaptation index exceeds `ADAPTATION_INDEX_EPSILON`.
+    pub fn is_adapting(&self) -> bool {
+        self.adaptation_index() > Self::ADAPTATION_INDEX_EPSILON
+    }
+
+    // Quantifies whether firing is signal-driven or noise-driven, as the threshold margin in units of membrane noise.
+    // Returns: `(mp - tp) / params.noise_std`; large positive values mean reliable firing and values near zero noise-dominated firing.
+    // Without noise the ratio is infinite, with the sign of the margin.
+    pub fn signal_to_noise_ratio(&self) -> f64 {
+        let margin = self.mp - self.tp;
+        if self.params.noise_std > 0.0 {
+            margin / self.params.noise_std
+        } else if margin >= 0.0 {
+            f64::INFINITY
+        } else {
+            f64::NEG_INFINITY
         }
     }
 
+    // Returns: True while the absolute refractory period lasts and incoming signals are blocked. Has no side effects.
+    pub fn is_refractory(&self) -> bool {
+        self.arp > 0.0
+    }
+
+    // Advances the absolute refractory period by one blocked signal, shortening it in proportion to the firing rate.
+    // Does nothing once the period has ended.
+    pub fn advance_refractory(&mut self) {
+        if self.is_refractory() {
+            self.arp -= Self::ABSOLUTE_REFRACTORY_PERIOD_DECREASE_FACTOR * self.fr;
+            self.arp = self.arp.clamp(0.0, Self::BASE_ABSOLUTE_REFRACTORY_PERIOD);
+        }
+    }
+
+    // Classifies the neuron's activity state from its threshold distance and refractory status.
+    // A neuron at threshold is `Firing` until `detect` discharges its accumulated potential, then `Refractory`
+    // while the absolute refractory period lasts.
+    // Returns: The current `FiringState`.
+    pub fn firing_state(&self) -> FiringState {
+        let distance = self.threshold_distance();
+        if distance <= 0.0 && (self.ap > 0.0 || self.arp <= 0.0) {
+            FiringState::Firing
+        } else if self.is_refractory() {
+            FiringState::Refractory
+        } else if distance <= Self::NEAR_THRESHOLD_MARGIN {
+            FiringState::NearThreshold
+        } else {
+            FiringState::Quiescent
+        }
+    }
+
+    // Names the entries of `as_feature_vector`, in order.
+    // Returns: The feature names.
+    pub fn feature_names() -> [&'static str; Self::FEATURE_COUNT] {
+        [
+            "mp", "tp", "fr", "sw", "pr", "ltp", "ltd", "nc", "refractory",
+            "nt_contact", "nt_sensory", "nt_motor", "nrt_inhibitory", "nrt_excitatory",
+        ]
+    }
+
+    // Packs the neuron state into a fixed-length numeric vector for external machine learning models.
+    // The order is given by `feature_names`: `mp`, `tp`, `fr`, `sw`, `pr`, `ltp`, `ltd` and `nc` scaled from their
+    // valid ranges to [0, 1] (`ltd` by its magnitude), the refractory status as 1.0 or 0.0, then one-hot neuron and
+    // neurotransmitter types. Values outside their valid ranges are clamped.
+    // Returns: The feature vector.
+    pub fn as_feature_vector(&self) -> [f64; Self::FEATURE_COUNT] {
+        let scale = |value: f64, min: f64, max: f64| ((value - min) / (max - min)).clamp(0.0, 1.0);
+        let flag = |set: bool| if set { 1.0 } else { 0.0 };
+        [
+            scale(self.mp, Self::MIN_MEMBRANE_POTENTIAL, Self::MAX_MEMBRANE_POTENTIAL),
+            scale(self.tp, Self::MIN_THRESHOLD_POTENTIAL, Self::MAX_THRESHOLD_POTENTIAL),
+            scale(self.fr, 0.0, Self::MAX_FIRING_RATE),
+            scale(self.sw, Self::MIN_LTD, Self::MAX_LTP),
+            scale(self.pr, 0.0, Self::MAX_PLASTICITY_RATE),
+            scale(self.ltp, 0.0, Self::MAX_LTP),
+            scale(self.ltd, 0.0, Self::MIN_LTD),
+            scale(self.nc, 0.0, Self::MAX_NEUROTRANSMITTER_CONCENTRATION),
+            flag(self.arp > 0.0),
+            flag(self.nt == 0),
+            flag(self.nt == 1),
+            flag(self.nt == 2),
+            flag(self.nrt == 0),
+            flag(self.nrt == 1),
+        ]
+    }
+
+    // Reports whether `detect` would fire now, without firing or otherwise changing the neuron.
+    // Returns: True if the membrane potential is at or above the threshold.
+    pub fn will_fire(&self) -> bool {
+        self.mp >= self.tp
+    }
+
     // Detects neuronal signals based on membrane potential.
+    // A detection that does not fire counts as an idle tick, during which the receptors recover.
     // Returns: The generated signal if the membrane potential exceeds the threshold; otherwise, returns 0.0.
     pub fn detect(&mut self) -> f64 {
-        if self.mp >= self.tp {
+        if self.will_fire() {
             return self.fire(); // Return the generated signal
         }
 
+        self.recover_receptors();
         0.0 // No signal triggered, return no signal
     }
+
+    // Detects neuronal signals like `detect`, but reports a binary spike event instead of the graded signal.
+    // Returns: True if the neuron fired, which resets the accumulated potential as `detect` does.
+    pub fn detect_binary(&mut self) -> bool {
+        if self.will_fire() {
+            self.fire();
+            return true;
+        }
+
+        self.recover_receptors();
+        false
+    }
+
+    // Sets the seed of the neuron's random number generator, restarting its random stream.
+    // Parameters:
+    // - `seed`: The seed used by stochastic methods.
+    #[cfg(feature = "rand")]
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = Some(seed);
+        self.rng = None;
+    }
+
+    // Calculates the instantaneous firing probability of `detect_stochastic`, without drawing from the random number generator
+    // or changing the neuron, so a scheduler can rank neurons by how likely they are to fire.
+    // Returns: The logistic of `params.stochastic_gain * (mp - tp)`, in [0, 1]; 0.5 at threshold.
+    #[cfg(feature = "std")]
+    pub fn fire_probability(&self) -> f64 {
+        1.0 / (1.0 + (self.params.stochastic_gain * (self.tp - self.mp)).exp())
+    }
+
+    // Detects neuronal signals stochastically: the neuron fires with probability `fire_probability`.
+    // Returns: The generated signal if the neuron fires; otherwise, returns 0.0.
+    #[cfg(feature = "rand")]
+    pub fn detect_stochastic(&mut self) -> f64 {
+        let probability = self.fire_probability();
+        if self.rng().random_bool(probability) {
+            return self.fire(); // Return the generated signal
+        }
+
+        self.recover_receptors();
+        0.0 // No signal triggered, return no signal
+    }
+
     // Transmits signals and accumulates the membrane potential.
     // Parameters:
     // - `input`: The input signal value.
     // - `source`: An optional reference to the source neuron that sends the signal.
+    #[cfg(feature = "std")]
     pub async fn transmit(&mut self, input: f64, source: Option<&Neuron>) {
         // Signal delay
         if let Some(neuron) = source {
             self.signal_delay(neuron).await;
         }
 
-        // Check if the neuron is in a refractory state and cannot process incoming signals
-        if self.detection_arp() {
-            return;
+        self.process_signal(input, source.map(|neuron| (neuron.x, neuron.y, neuron.z)));
+    }
+
+    // Transmits signals like `transmit`, but bounds the signal delay by a timeout.
+    // If the delay exceeds the timeout the signal is dropped: the neuron state is left unchanged,
+    // as if the signal had been lost in transit.
+    // Parameters:
+    // - `input`: The input signal value.
+    // - `source`: An optional reference to the source neuron that sends the signal.
+    // - `timeout`: The maximum time to wait for the signal to arrive.
+    // Returns: `Ok(())` if the signal was applied, or `TransmitError::DelayTimeout` if it was dropped.
+    #[cfg(feature = "std")]
+    pub async fn transmit_with_timeout(&mut self, input: f64, source: Option<&Neuron>, timeout: Duration) -> Result<(), TransmitError> {
+        // Signal delay
+        if let Some(neuron) = source {
+            if tokio::time::timeout(timeout, self.signal_delay(neuron)).await.is_err() {
+                return Err(TransmitError::DelayTimeout {
+                    delay: self.delay_duration(neuron).unwrap_or_default(),
+                    timeout,
+                });
+            }
+        }
+
+        self.process_signal(input, source.map(|neuron| (neuron.x, neuron.y, neuron.z)));
+        Ok(())
+    }
+
+    // Transmits a signal without a source delay and accumulates the membrane potential.
+    // Parameters:
+    // - `input`: The input signal value.
+    pub fn transmit_sync(&mut self, input: f64) {
+        self.process_signal(input, None);
+    }
+
+    // Transmits the inputs arriving in one integration window as a single summed signal without a source delay.
+    // Positive inputs are excitatory and negative inputs inhibitory. In `Subtractive` mode the signal is their algebraic sum;
+    // in `Shunting` mode the excitatory sum is divided by `1 + inhibition / SHUNTING_INHIBITION_CONSTANT`, scaling it down
+    // proportionally instead, so inhibition alone cannot drive the neuron below rest.
+    // Parameters:
+    // - `inputs`: The input signal values of the window.
+    // - `mode`: How inhibitory inputs combine with excitatory ones.
+    pub fn transmit_many(&mut self, inputs: &[f64], mode: InhibitionMode) {
+        let excitation: f64 = inputs.iter().filter(|&&input| input > 0.0).sum();
+        let inhibition: f64 = inputs.iter().filter(|&&input| input < 0.0).map(|input| -input).sum();
+        let input = match mode {
+            InhibitionMode::Subtractive => excitation - inhibition,
+            InhibitionMode::Shunting => excitation / (1.0 + inhibition / Self::SHUNTING_INHIBITION_CONSTANT),
+        };
+        self.process_signal(input, None);
+    }
+
+    // Samples the neuron's input-output curve without changing its state.
+    // Parameters:
+    // - `inputs`: The input signal values to sweep; each is applied to a fresh copy of the neuron.
+    // Returns: The signal fired in response to each input, or 0.0 where the neuron stays below threshold.
+    pub fn response_curve(&self, inputs: &[f64]) -> Vec<f64> {
+        inputs.iter().map(|&input| {
+            let mut neuron = self.clone();
+            neuron.transmit_sync(input);
+            neuron.detect()
+        }).collect()
+    }
+
+    // Estimates the firing rate a neuron converges to under a constant input repeatedly applied with `transmit_sync`,
+    // solving the fixed point of `update_fr` in closed form instead of simulating.
+    // The accumulated potential settles where `params.leak_rate` balances the input (without leak it grows without bound
+    // in the direction of the input); in conductance mode the driving force shrinks as the membrane nears the reversal
+    // potential. The membrane then sits at `RESTING_POTENTIAL` plus that potential, independent of `params.tau`.
+    // If it clears the threshold even at `MAX_FIRING_RATE` the rate saturates; if it stays below the threshold at rate 0
+    // the rate decays to 0; in between, the rate settles where the rate-adapted threshold meets the membrane.
+    // The input gain uses the current `nc`, `rrp`, `receptor_sensitivity` and `ta`, and refractory blocking is ignored.
+    // Parameters:
+    // - `constant_input`: The sustained input signal value.
+    // Returns: The fixed point of `update_fr` for the sustained input.
+    pub fn equilibrium_firing_rate(&self, constant_input: f64) -> f64 {
+        let intensity = if constant_input.abs() >= Self::ACCUMULATED_POTENTIAL_CRITICAL_VALUE {
+            Self::ACCUMULATED_POTENTIAL_STIMULUS_INTENSITY
+        } else {
+            Self::ACCUMULATED_POTENTIAL_SLIGHT_INTENSITY
+        };
+        let gain = intensity * self.nc * self.rrp * self.receptor_sensitivity;
+        let leak = self.params.leak_rate;
+
+        // Steady accumulated potential, where the leak balances the input
+        let ap = if self.params.conductance_based {
+            let reversal = if constant_input >= 0.0 { self.params.reversal_exc } else { self.params.reversal_inh };
+            let drive = reversal - Self::RESTING_POTENTIAL;
+            let conductance = if drive == 0.0 { 0.0 } else { gain * constant_input.abs() / drive.abs() };
+            if leak + conductance > 0.0 { conductance * drive / (leak + conductance) } else { self.ap }
+        } else if leak > 0.0 {
+            gain * constant_input / leak
+        } else if gain * constant_input != 0.0 {
+            f64::INFINITY.copysign(gain * constant_input)
+        } else {
+            self.ap
+        };
+        let mp = (Self::RESTING_POTENTIAL + ap).clamp(Self::MIN_MEMBRANE_POTENTIAL, Self::MAX_MEMBRANE_POTENTIAL);
+
+        // Threshold without its firing rate term, as computed by `update_tp`
+        let rate_factor = if self.params.threshold_adaptation { self.params.threshold_potential_boost_factor_for_firing_rate } else { 0.0 };
+        let mut base_tp = Self::MIN_THRESHOLD_POTENTIAL;
+        if self.params.threshold_adaptation {
+            base_tp += Self::THRESHOLD_POTENTIAL_BOOST_FACTOR_FOR_ACCUMULATED_POTENTIAL * ap.max(0.0);
+            if self.params.cumulative_threshold_adaptation {
+                base_tp += self.ta;
+            }
+        }
+        let tp = |fr: f64| (base_tp + rate_factor * fr).min(Self::MAX_THRESHOLD_POTENTIAL);
+
+        let boosts = self.params.firing_rate_boost_factor > 0.0;
+        let decays = self.params.firing_rate_decrease_factor < 1.0;
+        if boosts && mp >= tp(Self::MAX_FIRING_RATE) {
+            Self::MAX_FIRING_RATE
+        } else if decays && mp < tp(0.0) {
+            0.0
+        } else if boosts && decays && rate_factor > 0.0 {
+            ((mp - base_tp) / rate_factor).clamp(0.0, Self::MAX_FIRING_RATE)
+        } else {
+            self.fr // Neither boost nor decay moves the rate
+        }
+    }
+
+    // Converts a target firing rate into the constant input current that sustains it, the inverse of `equilibrium_firing_rate`.
+    // The equilibrium rate never decreases as the input grows, so the input is found by bisection over [0, MAX_EXCITATORY_SIGNAL].
+    // Only a leaky neuron has intermediate equilibrium rates; without leak any excitation saturates the rate.
+    // The rate also jumps where the input crosses `ACCUMULATED_POTENTIAL_CRITICAL_VALUE`. A rate that no input sustains
+    // exactly maps to the smallest input reaching at least that rate, or to `MAX_EXCITATORY_SIGNAL` if none does.
+    // Parameters:
+    // - `rate`: The target firing rate, clamped to [0, MAX_FIRING_RATE].
+    // Returns: The constant input current whose equilibrium firing rate is closest to `rate`.
+    pub fn rate_to_input(&self, rate: f64) -> f64 {
+        let rate = rate.clamp(0.0, Self::MAX_FIRING_RATE);
+        let (mut low, mut high) = (0.0, Self::MAX_EXCITATORY_SIGNAL);
+        for _ in 0..Self::RATE_TO_INPUT_ITERATIONS {
+            let mid = (low + high) / 2.0;
+            if self.equilibrium_firing_rate(mid) < rate {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        high
+    }
+
+    // Applies an arriving signal: refractory check, potential and firing updates, then plasticity.
+    // Parameters:
+    // - `input`: The input signal value.
+    // - `source`: The coordinate of the source neuron, if any.
+    // Returns: False if the signal was blocked by the absolute refractory period; otherwise, true.
+    pub(crate) fn process_signal(&mut self, input: f64, source: Option<(usize, usize, usize)>) -> bool {
+        // Signals only carry a source under `std`, through `transmit` or a `Network`
+        #[cfg(feature = "std")]
+        let input = input * source.map_or(1.0, |pos| self.distance_attenuation(pos));
+        self.integrate_signal(input, source, 1.0, 1)
+    }
+
+    // Calculates the factor by which a signal from a source is scaled under `params.distance_attenuation`.
+    // Parameters:
+    // - `source`: The coordinate of the source neuron.
+    // Returns: The attenuation factor; 1.0 without attenuation or for a co-located source.
+    #[cfg(feature = "std")]
+    fn distance_attenuation(&self, source: (usize, usize, usize)) -> f64 {
+        let d = distance((self.x, self.y, self.z), source);
+        if d == 0.0 {
+            return 1.0;
+        }
+        match self.params.distance_attenuation {
+            DistanceAttenuation::None => 1.0,
+            DistanceAttenuation::InverseLinear => 1.0 / d,
+            DistanceAttenuation::InverseSquare => 1.0 / (d * d),
+        }
+    }
+
+    // Applies an input like `transmit_sync`, but integrates the potentials in `steps` explicit Euler sub-steps of size `dt`.
+    // Each sub-step adds `dt` of the input and removes `dt * params.leak_rate` of the accumulated potential;
+    // firing rate and plasticity are then updated once, as for a single transmitted signal.
+    // With `dt * steps == 1` and no leak, the result matches `transmit_sync`.
+    // Parameters:
+    // - `input`: The input signal value.
+    // - `dt`: The size of each sub-step.
+    // - `steps`: The number of sub-steps; at least one is taken.
+    pub fn integrate(&mut self, input: f64, dt: f64, steps: usize) {
+        self.integrate_signal(input, None, dt, steps);
+    }
+
+    // Applies an arriving signal in Euler sub-steps: refractory check, potential updates per sub-step, then firing and plasticity updates.
+    // Parameters:
+    // - `input`: The input signal value.
+    // - `source`: The coordinate of the source neuron, if any.
+    // - `dt`: The size of each sub-step.
+    // - `steps`: The number of sub-steps; at least one is taken.
+    // Returns: False if the signal was blocked by the absolute refractory period; otherwise, true.
+    fn integrate_signal(&mut self, input: f64, source: Option<(usize, usize, usize)>, dt: f64, steps: usize) -> bool {
+        // A frozen neuron ignores its inputs
+        if self.frozen {
+            return true;
+        }
+
+        // Check if the neuron is in a refractory state and cannot process incoming signals; a blocked signal advances the period
+        let refractory = self.is_refractory();
+        self.advance_refractory();
+        if refractory {
+            #[cfg(feature = "log")]
+            log::debug!("({}, {}, {}) -> transmit blocked by refractory period: input {}", self.x, self.y, self.z, input);
+            return false;
+        }
+
+        // Plasticity is throttled by how far the relative refractory period had recovered when the signal arrived
+        let recovery = self.rrp / Self::BASE_RELATIVE_REFRACTORY_PERIOD;
+
+        if input >= 0.0 {
+            self.exc_drive += input;
+        } else {
+            self.inh_drive += input;
+        }
+
+        // Membrane noise perturbs the accumulated potential once per signal
+        #[cfg(feature = "rand")]
+        if self.params.noise_std > 0.0 {
+            self.ap += self.params.noise_std * standard_normal(self.rng());
         }
 
         // Directly use input to accumulate membrane potential
-        self.update_ap(input);
-        self.update_mp();
-        self.update_tp();
+        for _ in 0..steps.max(1) {
+            self.update_ap(input, dt);
+            self.update_mp(dt);
+            self.update_tp();
+        }
         self.update_rp();
         self.update_fr();
-        self.update_pr();
-        self.update_ltp(input);
-        self.update_ltd(input);
-        self.update_sst(input);
-        self.update_sw();
+        if self.plasticity_enabled {
+            self.update_pr(recovery);
+            self.update_ltp(input);
+            self.update_ltd(input);
+            self.update_sst(input);
+            self.apply_plasticity_rule(input, recovery);
+        }
+
+        // A presynaptic partner whose signal brings this neuron to threshold has co-activated with it
+        if let (Some(counts), Some(pos)) = (self.coactivations.as_mut(), source) {
+            if self.mp >= self.tp && self.dc.contains(&pos) {
+                *counts.entry(pos).or_insert(0) += 1;
+            }
+        }
+
+        true
+    }
+
+    // Enables co-activation tracking: each time a signal from a presynaptic partner brings this neuron to threshold, the partner's count is incremented.
+    // Counts are kept in memory only and are not serialized.
+    pub fn enable_coactivation_tracking(&mut self) {
+        self.coactivations.get_or_insert_with(CoactivationMap::new);
+    }
+
+    // Parameters:
+    // - `partner_pos`: The coordinate of the presynaptic partner.
+    // Returns: The number of co-activations recorded with the partner; 0 if tracking is disabled.
+    pub fn coactivation(&self, partner_pos: impl Into<Coord3>) -> u64 {
+        let partner_pos = coord::into_tuple(partner_pos);
+        self.coactivations.as_ref().and_then(|counts| counts.get(&partner_pos).copied()).unwrap_or(0)
+    }
+
+    // Replaces the learning rule used to update the synaptic weight.
+    // Parameters:
+    // - `rule`: The plasticity rule applied after each transmitted signal.
+    pub fn set_plasticity_rule(&mut self, rule: impl PlasticityRule + 'static) {
+        self.plasticity_rule = Arc::new(rule);
+    }
+
+    // Replaces the built-in formula computing the signal emitted when the neuron fires.
+    // `fire` still resets the accumulated potential after calling the transform. The transform is not serialized.
+    // Parameters:
+    // - `transform`: The function computing the output signal from the neuron's state.
+    pub fn set_fire_transform(&mut self, transform: Box<dyn Fn(&Neuron) -> f64 + Send + Sync>) {
+        self.fire_transform = Some(FireTransform(Arc::from(transform)));
+    }
+
+    // Restores the built-in formula computing the signal emitted when the neuron fires.
+    pub fn clear_fire_transform(&mut self) {
+        self.fire_transform = None;
+    }
+
+    // Returns: The neuron's random number generator, seeded from `seed` on first use.
+    #[cfg(feature = "rand")]
+    fn rng(&mut self) -> &mut SmallRng {
+        let seed = self.seed;
+        self.rng.get_or_insert_with(|| match seed {
+            Some(seed) => SmallRng::seed_from_u64(seed),
+            None => rand::make_rng(),
+        })
+    }
+
+    // Returns: The default plasticity toggle (enabled).
+    fn default_plasticity_enabled() -> bool {
+        true
+    }
+
+    // Returns: The values of the bounded fields, in the order of `FIELD_BOUNDS`.
+    fn bounded_fields(&self) -> [f64; 12] {
+        [self.mp, self.tp, self.fr, self.sw, self.sst, self.pr, self.ltp, self.ltd, self.arp, self.rrp, self.receptor_sensitivity, self.nc]
+    }
+
+    // Returns: Mutable references to the bounded fields, in the order of `FIELD_BOUNDS`.
+    fn bounded_fields_mut(&mut self) -> [&mut f64; 12] {
+        [&mut self.mp, &mut self.tp, &mut self.fr, &mut self.sw, &mut self.sst, &mut self.pr, &mut self.ltp, &mut self.ltd, &mut self.arp, &mut self.rrp, &mut self.receptor_sensitivity, &mut self.nc]
+    }
+
+    // Returns: The scalar state fields, in the order `acv`, `ap`, `tp`, `mp`, `fr`, `ta`, `sw`, `sst`, `pr`, `arp`, `rrp`, `nc`, `receptor_sensitivity`, `ltp`, `ltd`.
+    fn scalar_fields(&self) -> [f64; 15] {
+        [self.acv, self.ap, self.tp, self.mp, self.fr, self.ta, self.sw, self.sst, self.pr, self.arp, self.rrp, self.nc, self.receptor_sensitivity, self.ltp, self.ltd]
+    }
+
+    // Returns: Mutable references to the scalar state fields, in the order of `scalar_fields`.
+    fn scalar_fields_mut(&mut self) -> [&mut f64; 15] {
+        [&mut self.acv, &mut self.ap, &mut self.tp, &mut self.mp, &mut self.fr, &mut self.ta, &mut self.sw, &mut self.sst, &mut self.pr, &mut self.arp, &mut self.rrp, &mut self.nc, &mut self.receptor_sensitivity, &mut self.ltp, &mut self.ltd]
+    }
+
+    // Returns: The tunable parameters evolved by `mutate` and `crossover`, in the order `sw`, `sst`, `pr`, `ltp`, `ltd`, `nc`.
+    #[cfg(feature = "rand")]
+    fn tunable_fields(&self) -> [f64; 6] {
+        [self.sw, self.sst, self.pr, self.ltp, self.ltd, self.nc]
+    }
+
+    // Returns: Mutable references to the tunable parameters, in the order of `tunable_fields`.
+    #[cfg(feature = "rand")]
+    fn tunable_fields_mut(&mut self) -> [&mut f64; 6] {
+        [&mut self.sw, &mut self.sst, &mut self.pr, &mut self.ltp, &mut self.ltd, &mut self.nc]
+    }
+
+    // Returns: The default receptor sensitivity (fully sensitive).
+    fn default_receptor_sensitivity() -> f64 {
+        Self::MAX_RECEPTOR_SENSITIVITY
+    }
+
+    // Returns: The default additive plasticity rule.
+    fn default_plasticity_rule() -> Arc<dyn PlasticityRule> {
+        Arc::new(AdditiveRule)
+    }
+
+    // Summarizes the neuron's learning state.
+    // Returns: The plasticity parameters together with the net potentiation (`ltp + ltd`).
+    pub fn plasticity_summary(&self) -> PlasticitySummary {
+        PlasticitySummary {
+            ltp: self.ltp,
+            ltd: self.ltd,
+            pr: self.pr,
+            sst: self.sst,
+            sw: self.sw,
+            net_potentiation: self.ltp + self.ltd,
+        }
+    }
+
+    // Desensitizes the receptors, reducing the gain applied to later inputs. Called on every fire.
+    // The reduction is set by `params.receptor_desensitization_factor` (`RECEPTOR_DESENSITIZATION_FACTOR` by default; 0.0 disables it).
+    pub fn desensitize(&mut self) {
+        self.receptor_sensitivity *= 1.0 - self.params.receptor_desensitization_factor;
+    }
+
+    // Recovers receptor sensitivity during an idle tick, moving it toward full sensitivity
+    // by `params.receptor_recovery_factor` of the remaining gap. Called by every detection that does not fire.
+    pub fn recover_receptors(&mut self) {
+        if self.frozen {
+            return;
+        }
+        self.receptor_sensitivity += (Self::MAX_RECEPTOR_SENSITIVITY - self.receptor_sensitivity) * self.params.receptor_recovery_factor;
+    }
+
+    // Relaxes the cumulative threshold adaptation during an idle tick, decaying `ta` by `THRESHOLD_ADAPTATION_DECAY_FACTOR`
+    // of its value and lowering the threshold accordingly. Does nothing unless `params.cumulative_threshold_adaptation` is set.
+    pub fn relax_threshold(&mut self) {
+        if self.frozen || !self.params.cumulative_threshold_adaptation {
+            return;
+        }
+        self.ta -= Self::THRESHOLD_ADAPTATION_DECAY_FACTOR * self.ta;
+        self.update_tp();
+    }
+
+    // Applies homeostatic synaptic scaling, multiplying the synaptic weight to drive the firing rate toward a target.
+    // Unlike the Hebbian plasticity applied by `transmit`, scaling is meant to be called on a slower timescale.
+    // Parameters:
+    // - `target_fr`: The desired firing rate.
+    // - `rate`: The scaling rate; the weight changes by `rate * (target_fr - fr)` of its current value.
+    pub fn synaptic_scaling(&mut self, target_fr: f64, rate: f64) {
+        self.sw *= 1.0 + rate * (target_fr - self.fr);
+        self.sw = self.sw.clamp(Self::MIN_LTD, Self::MAX_LTP);
     }
 
     // Calculates the distance between this neuron and another neuron.
     // Parameters:
     // - `other`: A reference to the other neuron to calculate distance from.
     // Returns: The Euclidean distance between the two neurons.
+    #[cfg(feature = "std")]
     fn calculate_distance(&self, other: &Neuron) -> f64 {
-        let xd = (self.x - other.x).pow(2);
-        let yd = (self.y - other.y).pow(2);
-        let zd = (self.z - other.z).pow(2);
-        ((xd + yd + zd) as f64).sqrt() // Return the Euclidean distance
+        distance((self.x, self.y, self.z), (other.x, other.y, other.z))
     }
 
     // Calculates and applies a delay to simulate signal transmission between neurons.
     // Parameters:
     // - `source`: A reference to the source neuron.
+    #[cfg(feature = "std")]
     async fn signal_delay(&self, source: &Neuron) {
+        if let Some(delay) = self.delay_duration(source) {
+            sleep(delay).await;
+        }
+    }
+
+    // Calculates the transmission delay of a signal from the source neuron.
+    // The conduction delay is rounded to whole milliseconds, then the receiving neuron's `params.min_synaptic_delay`
+    // is added, so even co-located neurons incur a baseline synaptic delay.
+    // Parameters:
+    // - `source`: A reference to the source neuron.
+    // Returns: The delay, or `None` if the signal arrives without delay.
+    #[cfg(feature = "std")]
+    fn delay_duration(&self, source: &Neuron) -> Option<Duration> {
+        let mut millis = self.params.min_synaptic_delay.max(0.0);
         if source.acv > 0.0 && source.pr > 0.0 {
             let unit = self.calculate_distance(source);
             if unit > 0.0 {
-                let millis = (unit / (source.acv * source.pr)).round() as u64;
-                sleep(Duration::from_millis(millis)).await;
+                millis += (unit / (source.acv * source.pr)).round();
             }
         }
+
+        (millis > 0.0).then(|| Duration::from_secs_f64(millis / 1000.0))
+    }
+
+    // Calculates the signal the neuron emits when it fires, without changing its state.
+    // Returns: The output of the custom fire transform if set; otherwise, the built-in signal for the neurotransmitter type.
+    fn output_signal(&self) -> f64 {
+        match (&self.fire_transform, self.nrt) {
+            (Some(FireTransform(transform)), _) => transform(self), // Custom signal
+            (None, 1) => (self.ap * (Self::FIRING_RATE_BOOST_FACTOR / self.fr)).clamp(Self::MIN_EXCITATORY_SIGNAL, Self::MAX_EXCITATORY_SIGNAL), // Excitatory signal
+            (None, 0) => (-self.ap * (Self::FIRING_RATE_BOOST_FACTOR / self.fr)).clamp(Self::MIN_INHIBITORY_SIGNAL, Self::MAX_INHIBITORY_SIGNAL), // Inhibitory signal
+            (None, _) => 0.0, // Unknown type
+        }
     }
 
     // Fires the neuron, generating a signal based on its type.
     // Returns: The adjusted signal output based on the neuron's neurotransmitter type.
     fn fire(&mut self) -> f64 {
-        let output = match self.nrt {
-            1 => (self.ap * (Self::FIRING_RATE_BOOST_FACTOR / self.fr)).clamp(Self::MIN_EXCITATORY_SIGNAL, Self::MAX_EXCITATORY_SIGNAL), // Excitatory signal
-            0 => (-self.ap * (Self::FIRING_RATE_BOOST_FACTOR / self.fr)).clamp(Self::MIN_INHIBITORY_SIGNAL, Self::MAX_INHIBITORY_SIGNAL), // Inhibitory signal
-            _ => 0.0, // Unknown type
-        };
+        let output = self.output_signal();
+        // A frozen neuron emits its static signal without discharging
+        if self.frozen {
+            return output;
+        }
         self.ap = 0.0; // Reset accumulated potential after firing
-    
+        self.exc_drive = 0.0;
+        self.inh_drive = 0.0;
+        self.fire_count += 1;
+        self.desensitize();
+        if self.params.cumulative_threshold_adaptation {
+            self.ta = (self.ta + Self::THRESHOLD_ADAPTATION_INCREMENT).min(Self::MAX_THRESHOLD_POTENTIAL - Self::MIN_THRESHOLD_POTENTIAL);
+            self.update_tp();
+        }
+
+        #[cfg(feature = "log")]
+        log::trace!("({}, {}, {}) -> fire: signal {}", self.x, self.y, self.z, output);
+
         output // Return the adjusted signal
     }
 
-    // Updates the accumulated potential based on the input signal.
+    // Converts a conductance input into the current it drives at the present membrane potential.
+    // Positive inputs are excitatory and negative inputs inhibitory conductances; each drives the membrane toward its
+    // reversal potential in `params`, in proportion to the driving force `reversal - mp`. The driving force is normalized
+    // by its value at rest, so at rest a conductance input acts like the equivalent current input.
+    // Parameters:
+    // - `input`: The signed conductance.
+    // Returns: The equivalent current input.
+    fn synaptic_current(&self, input: f64) -> f64 {
+        let reversal = if input >= 0.0 { self.params.reversal_exc } else { self.params.reversal_inh };
+        let rest_force = (reversal - Self::RESTING_POTENTIAL).abs();
+        if rest_force == 0.0 {
+            return 0.0;
+        }
+        input.abs() * (reversal - self.mp) / rest_force
+    }
+
+    // Updates the accumulated potential based on the input signal over a time step, after the leak.
     // Parameters:
     // - `input`: The input signal value to update the accumulated potential.
-    fn update_ap(&mut self, input: f64) {
+    // - `dt`: The fraction of the input applied, and the time step of the leak.
+    fn update_ap(&mut self, input: f64, dt: f64) {
+        let input = if self.params.conductance_based { self.synaptic_current(input) } else { input };
+        self.ap -= self.params.leak_rate * self.ap * dt;
         if input.abs() >= Self::ACCUMULATED_POTENTIAL_CRITICAL_VALUE {
-            self.ap += Self::ACCUMULATED_POTENTIAL_STIMULUS_INTENSITY * input * self.nc * self.rrp;
+            self.ap += Self::ACCUMULATED_POTENTIAL_STIMULUS_INTENSITY * input * dt * self.nc * self.rrp * self.receptor_sensitivity;
         } else {
-            self.ap += Self::ACCUMULATED_POTENTIAL_SLIGHT_INTENSITY * input * self.nc * self.rrp;
+            self.ap += Self::ACCUMULATED_POTENTIAL_SLIGHT_INTENSITY * input * dt * self.nc * self.rrp * self.receptor_sensitivity;
         }
     }
 
     // Updates the membrane potential based on accumulated potential.
-    fn update_mp(&mut self) {
-        self.mp = (Self::RESTING_POTENTIAL + self.ap).clamp(Self::MIN_MEMBRANE_POTENTIAL, Self::MAX_MEMBRANE_POTENTIAL);
+    // Like an RC circuit, it moves toward `RESTING_POTENTIAL + ap` by a fraction `dt / params.tau` per step,
+    // and snaps to it when the time constant does not exceed the step.
+    // Parameters:
+    // - `dt`: The time step.
+    fn update_mp(&mut self, dt: f64) {
+        let target = (Self::RESTING_POTENTIAL + self.ap).clamp(Self::MIN_MEMBRANE_POTENTIAL, Self::MAX_MEMBRANE_POTENTIAL);
+        if self.params.tau <= dt {
+            self.mp = target;
+        } else {
+            self.mp += (target - self.mp) * dt / self.params.tau;
+        }
     }
 
     // Updates the threshold potential based on accumulated potential and firing rate.
+    // A neuron with threshold adaptation disabled keeps a fixed threshold.
+    // With cumulative adaptation enabled, the persistent component `ta` is added on top; it decays only through `relax_threshold`.
     fn update_tp(&mut self) {
         self.tp = Self::MIN_THRESHOLD_POTENTIAL;
+        if !self.params.threshold_adaptation {
+            return;
+        }
         if self.ap > 0.0 {
             self.tp += Self::THRESHOLD_POTENTIAL_BOOST_FACTOR_FOR_ACCUMULATED_POTENTIAL * self.ap;
         }
-        self.tp += Self::THRESHOLD_POTENTIAL_BOOST_FACTOR_FOR_FIRING_RATE * self.fr;
-        self.tp = self.tp.min(Self::MAX_THRESHOLD_POTENTIAL);
-    }
-
-    // Detection of the absolute refractory threshold.
-    fn detection_arp(&mut self) -> bool {
-        if self.arp > 0.0 {
-            self.arp -= Self::ABSOLUTE_REFRACTORY_PERIOD_DECREASE_FACTOR * self.fr;
-            self.arp = self.arp.clamp(0.0, Self::BASE_ABSOLUTE_REFRACTORY_PERIOD);
-            return true;
+        self.tp += self.params.threshold_potential_boost_factor_for_firing_rate * self.fr;
+        if self.params.cumulative_threshold_adaptation {
+            self.tp += self.ta;
         }
-        return false;
+        self.tp = self.tp.min(Self::MAX_THRESHOLD_POTENTIAL);
     }
 
     // Updates the refractory threshold.
@@ -299,17 +1317,19 @@ impl Neuron {
     // Updates the firing rate based on membrane potential and threshold.
     fn update_fr(&mut self) {
         if self.mp >= self.tp {
-            self.fr += Self::FIRING_RATE_BOOST_FACTOR * (self.ap / Self::ACCUMULATED_POTENTIAL_CRITICAL_VALUE);
+            self.fr += self.params.firing_rate_boost_factor * (self.ap / Self::ACCUMULATED_POTENTIAL_CRITICAL_VALUE);
         } else {
-            self.fr *= Self::FIRING_RATE_DECREASE_FACTOR;
+            self.fr *= self.params.firing_rate_decrease_factor;
         }
         self.fr = self.fr.min(Self::MAX_FIRING_RATE);
     }
 
     // Updates the plasticity rate based on membrane potential and threshold.
-    fn update_pr(&mut self) {
+    // Parameters:
+    // - `recovery`: The relative refractory recovery fraction scaling the boost.
+    fn update_pr(&mut self, recovery: f64) {
         if self.mp >= self.tp {
-            self.pr += Self::PLASTICITY_RATE_BOOST_FACTOR * self.fr;
+            self.pr += Self::PLASTICITY_RATE_BOOST_FACTOR * self.fr * recovery;
         } else {
             self.pr *= Self::PLASTICITY_RATE_DECREASE_FACTOR;
         }
@@ -348,9 +1368,41 @@ impl Neuron {
         self.sst = self.sst.clamp(Self::MIN_LTD, Self::MAX_LTP);
     }
 
+    // Applies the plasticity rule, scaling the resulting synaptic weight change.
+    // Parameters:
+    // - `input`: The input signal value passed to the rule.
+    // - `recovery`: The relative refractory recovery fraction scaling the weight change.
+    fn apply_plasticity_rule(&mut self, input: f64, recovery: f64) {
+        let sw = self.sw;
+        let rule = Arc::clone(&self.plasticity_rule);
+        rule.apply(self, input);
+        self.sw = sw + (self.sw - sw) * recovery;
+    }
+
     // Updates the synaptic weight based on LTD, LTP and plasticity rate.
-    fn update_sw(&mut self) {
+    pub(crate) fn update_sw(&mut self) {
         self.sw += (self.ltp + self.ltd) * self.pr;
         self.sw = self.sw.clamp(Self::MIN_LTD, Self::MAX_LTP);
     }
 }
+
+// Calculates the Euclidean distance between two coordinates.
+// Parameters:
+// - `a`: The first coordinate.
+// - `b`: The second coordinate.
+// Returns: The Euclidean distance between the two coordinates.
+#[cfg(feature = "std")]
+pub(crate) fn distance(a: (usize, usize, usize), b: (usize, usize, usize)) -> f64 {
+    Coord3::from(a).distance_to(b.into())
+}
+
+// Draws a sample from the standard normal distribution with the Box-Muller transform.
+// Parameters:
+// - `rng`: The random number generator drawing the sample.
+// Returns: The sample.
+#[cfg(feature = "rand")]
+fn standard_normal(rng: &mut impl Rng) -> f64 {
+    let u1: f64 = 1.0 - rng.random::<f64>(); // In (0, 1], so the logarithm is finite
+    let u2: f64 = rng.random();
+    (-2.0 * u1.ln()).sqrt() * (2.0 * core::f64::consts::PI * u2).cos()
+}
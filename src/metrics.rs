@@ -0,0 +1,44 @@
+use std::time::Duration;
+
+// Throughput counters collected by `Network::tick`, for profiling simulation speed.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Metrics {
+    pub ticks: u64,        // ticks executed
+    pub transmits: u64,    // signals delivered to target neurons, including blocked ones
+    pub fires: u64,        // neurons that fired
+    pub blocked: u64,      // delivered signals dropped by the absolute refractory period
+    pub elapsed: Duration, // wall-clock time spent inside `tick`
+}
+
+impl Metrics {
+    // Returns: The number of delivered signals per wall-clock second, or 0.0 before any time has elapsed.
+    pub fn transmits_per_second(&self) -> f64 {
+        self.per_second(self.transmits)
+    }
+
+    // Returns: The number of fires per wall-clock second, or 0.0 before any time has elapsed.
+    pub fn fires_per_second(&self) -> f64 {
+        self.per_second(self.fires)
+    }
+
+    // Returns: The number of ticks per wall-clock second, or 0.0 before any time has elapsed.
+    pub fn ticks_per_second(&self) -> f64 {
+        self.per_second(self.ticks)
+    }
+
+    // Returns: The fraction of delivered signals blocked by the refractory period, or 0.0 if none were delivered.
+    pub fn blocked_fraction(&self) -> f64 {
+        if self.transmits == 0 {
+            return 0.0;
+        }
+        self.blocked as f64 / self.transmits as f64
+    }
+
+    // Parameters:
+    // - `count`: The event count to convert.
+    // Returns: The count divided by the elapsed seconds, or 0.0 before any time has elapsed.
+    fn per_second(&self, count: u64) -> f64 {
+        let seconds = self.elapsed.as_secs_f64();
+        if seconds > 0.0 { count as f64 / seconds } else { 0.0 }
+    }
+}
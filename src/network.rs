@@ -0,0 +1,1545 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BTreeMap, BinaryHeap, HashMap, HashSet, VecDeque};
+use std::time::Instant;
+use crate::coord::into_tuple;
+use crate::{distance, Coord3, CycleError, Metrics, Neuron, NeuronError, NeuronId, Neurotransmitter, SpikeRaster};
+#[cfg(feature = "rand")]
+use rand::{seq::IndexedRandom, Rng, RngExt};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+// Probability that `Network::evolve` mutates each tunable parameter of a regenerated neuron.
+#[cfg(feature = "rand")]
+const EVOLVE_MUTATION_RATE: f64 = 0.2;
+
+// Standard deviation of the mutations applied by `Network::evolve`.
+#[cfg(feature = "rand")]
+const EVOLVE_MUTATION_MAGNITUDE: f64 = 0.05;
+
+// Granularity to which `Network::fingerprint` rounds synaptic weights.
+const FINGERPRINT_WEIGHT_PRECISION: f64 = 1e-9;
+
+// FNV-1a 64-bit parameters used by `Network::fingerprint`.
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+// A connection between two neuron coordinates with its signed synaptic weight.
+type Edge = ((usize, usize, usize), (usize, usize, usize), f64);
+
+// The specification of one layer of a network built by `Network::from_layers`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LayerSpec {
+    pub count: usize,                  // number of neurons in the layer
+    pub nt: u32,                       // neuron type ( 0 = Contact , 1 = Sensory , 2 = Motor )
+    pub nrt: Neurotransmitter,         // neurotransmitter type of every neuron in the layer
+    pub connection_probability: f64,   // probability of each connection to a neuron of the next layer
+}
+
+// The neurons that fired during one tick of `Network::run_with_input`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FiringSnapshot {
+    pub tick: u64,                  // clock value of the tick
+    pub fired: Vec<(NeuronId, f64)>, // neurons that fired, paired with their output signal
+}
+
+// The synaptic weights of a network at one moment, keyed by neuron coordinate, taken by `Network::snapshot_weights`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeightSnapshot {
+    weights: HashMap<(usize, usize, usize), f64>, // synaptic weight of each neuron
+}
+
+#[derive(Debug, Clone)]
+pub struct Network {
+    neurons: Vec<Neuron>,                            // neurons in insertion order
+    index: HashMap<(usize, usize, usize), NeuronId>, // neuron coordinate to identifier
+    plasticity_enabled: bool,                        // plasticity toggle applied to every neuron
+    clock: u64,                                      // logical time in ticks
+    pruning_interval: u64,                           // ticks between automatic pruning ( 0 = disabled )
+    recording: bool,                                 // whether `tick` records spikes into the raster
+    raster: SpikeRaster,                             // spikes recorded while recording is enabled
+    metrics: Metrics,                                // throughput counters updated by `tick`
+    dropped: HashSet<NeuronId>,                      // neurons silenced during the next tick
+    scheduled: BTreeMap<u64, Vec<(NeuronId, f64)>>,  // inputs queued for delivery, keyed by tick
+}
+
+impl Default for Network {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Network {
+    // Creates a new, empty Network instance.
+    pub fn new() -> Self {
+        Network {
+            neurons: Vec::new(),
+            index: HashMap::new(),
+            plasticity_enabled: true,
+            clock: 0,
+            pruning_interval: 0,
+            recording: false,
+            raster: SpikeRaster::new(),
+            metrics: Metrics::default(),
+            dropped: HashSet::new(),
+            scheduled: BTreeMap::new(),
+        }
+    }
+
+    // Builds a layered network with per-layer neuron types and wiring densities.
+    // Layer `i` occupies the plane `x = i`, its neurons placed along `y` with their axons pointing toward the next layer.
+    // Each neuron is connected to each neuron of the next layer with its layer's `connection_probability`;
+    // a neuron stops gaining connections once it reaches its fan-out limit.
+    // Parameters:
+    // - `layers`: The layer specifications, from input to output.
+    // - `rng`: The random number generator drawing the connections.
+    // Returns: The network, with identifiers assigned layer by layer.
+    #[cfg(feature = "rand")]
+    pub fn from_layers(layers: &[LayerSpec], rng: &mut impl Rng) -> Network {
+        let mut network = Network::new();
+        let mut ranges = Vec::with_capacity(layers.len());
+        for (x, layer) in layers.iter().enumerate() {
+            let start = network.len();
+            for y in 0..layer.count {
+                network.add_neuron(Neuron::new(x, y, 0, x + 1, y, 0, layer.nt, layer.nrt.into()));
+            }
+            ranges.push(start..network.len());
+        }
+
+        for (i, layer) in layers.iter().enumerate().take(layers.len().saturating_sub(1)) {
+            let probability = layer.connection_probability.clamp(0.0, 1.0);
+            for from in ranges[i].clone() {
+                for to in ranges[i + 1].clone() {
+                    if rng.random_bool(probability) && network.connect(from, to).is_err() {
+                        break; // Fan-out limit reached
+                    }
+                }
+            }
+        }
+        network
+    }
+
+    // Builds a network from a weighted adjacency matrix.
+    // Each neuron is a contact neuron whose axon shares its coordinate. Since a neuron carries a single synaptic weight,
+    // its `sw` is set to the mean magnitude of the nonzero weights in its row.
+    // Parameters:
+    // - `positions`: The coordinate of each neuron; all must be distinct.
+    // - `weights`: A square matrix where `weights[i][j] != 0.0` connects neuron `i` to neuron `j`; the sign must match `nrt[i]`.
+    // - `nrt`: The neurotransmitter type of each neuron.
+    // Returns: The network, or an error if the dimensions, positions, or weight signs are invalid.
+    pub fn from_adjacency_matrix(positions: &[(usize, usize, usize)], weights: &[Vec<f64>], nrt: &[Neurotransmitter]) -> Result<Network, NeuronError> {
+        let n = positions.len();
+        if weights.len() != n {
+            return Err(NeuronError::DimensionMismatch { expected: n, found: weights.len() });
+        }
+        if let Some(row) = weights.iter().find(|row| row.len() != n) {
+            return Err(NeuronError::DimensionMismatch { expected: n, found: row.len() });
+        }
+        if nrt.len() != n {
+            return Err(NeuronError::DimensionMismatch { expected: n, found: nrt.len() });
+        }
+
+        let mut network = Network::new();
+        for (&(x, y, z), &kind) in positions.iter().zip(nrt) {
+            if network.id_of((x, y, z)).is_some() {
+                return Err(NeuronError::DuplicatePosition((x, y, z)));
+            }
+            network.add_neuron(Neuron::new(x, y, z, x, y, z, 0, kind.into()));
+        }
+
+        for (from, row) in weights.iter().enumerate() {
+            let sign = if nrt[from] == Neurotransmitter::Excitatory { 1.0 } else { -1.0 };
+            let mut total = 0.0;
+            let mut count = 0;
+            for (to, &weight) in row.iter().enumerate() {
+                if weight == 0.0 {
+                    continue;
+                }
+                if from == to {
+                    return Err(NeuronError::SelfConnection(from));
+                }
+                if weight * sign < 0.0 {
+                    return Err(NeuronError::WeightSignMismatch { from, to });
+                }
+                network.connect(from, to)?;
+                total += weight.abs();
+                count += 1;
+            }
+            if count > 0 {
+                network.neurons[from].sw = (total / count as f64).clamp(Neuron::MIN_LTD, Neuron::MAX_LTP);
+            }
+        }
+
+        Ok(network)
+    }
+
+    // Exports the network as a weighted adjacency matrix.
+    // Returns: A square matrix in identifier order where entry `[i][j]` is neuron `i`'s synaptic weight, negated for inhibitory neurons, if `i` connects to `j`; otherwise 0.0.
+    pub fn to_adjacency_matrix(&self) -> Vec<Vec<f64>> {
+        let mut matrix = vec![vec![0.0; self.neurons.len()]; self.neurons.len()];
+        for (from, row) in matrix.iter_mut().enumerate() {
+            let neuron = &self.neurons[from];
+            let weight = signed_weight(neuron);
+            for to in self.targets(from) {
+                row[to] = weight;
+            }
+        }
+        matrix
+    }
+
+    // Exports the network as a sparse weight matrix in coordinate (COO) format.
+    // Returns: The neuron identifier of each matrix row and column, and one `(row, col, weight)` triple per connection,
+    // sorted by row then column, with the same signed weights as `to_adjacency_matrix`. Absent edges produce no entries.
+    pub fn to_sparse_coo(&self) -> (Vec<NeuronId>, Vec<(usize, usize, f64)>) {
+        let ids: Vec<NeuronId> = (0..self.neurons.len()).collect();
+        let triples = ids.iter()
+            .flat_map(|&from| {
+                let weight = signed_weight(&self.neurons[from]);
+                self.targets(from).into_iter().map(move |to| (from, to, weight))
+            })
+            .collect();
+        (ids, triples)
+    }
+
+    // Adds a neuron to the network.
+    // Parameters:
+    // - `neuron`: The neuron to add; its coordinate must not already be occupied. It adopts the network's plasticity toggle.
+    // Returns: The identifier assigned to the neuron.
+    pub fn add_neuron(&mut self, mut neuron: Neuron) -> NeuronId {
+        let pos = (neuron.x, neuron.y, neuron.z);
+        if self.index.contains_key(&pos) {
+            panic!("error: {:?} : position is already occupied", pos);
+        }
+
+        let id = self.neurons.len();
+        neuron.plasticity_enabled = self.plasticity_enabled;
+        self.index.insert(pos, id);
+        self.neurons.push(neuron);
+        id
+    }
+
+    // Copies the neurons within a bounding box into a standalone network, leaving this network unchanged.
+    // Connections to coordinates outside the box are clipped, so the copy is self-contained.
+    // Parameters:
+    // - `bounds`: The inclusive minimum and maximum corners of the box.
+    // Returns: The sub-network of the in-bounds neurons, in their original relative order.
+    pub fn clone_region(&self, bounds: (impl Into<Coord3>, impl Into<Coord3>)) -> Network {
+        let bounds = (into_tuple(bounds.0), into_tuple(bounds.1));
+        let mut region = Network::new();
+        region.plasticity_enabled = self.plasticity_enabled;
+        for neuron in self.neurons.iter().filter(|neuron| within_bounds((neuron.x, neuron.y, neuron.z), bounds)) {
+            let mut neuron = neuron.clone();
+            neuron.ac.retain(|&pos| within_bounds(pos, bounds));
+            neuron.dc.retain(|&pos| within_bounds(pos, bounds));
+            region.add_neuron(neuron);
+        }
+        region
+    }
+
+    // Splits the network in two by a per-neuron predicate, leaving this network unchanged.
+    // Connections within each side are preserved; connections between the sides, or to coordinates outside the network, are clipped.
+    // Parameters:
+    // - `pred`: The predicate selecting the neurons of the first sub-network; the rest form the second.
+    // Returns: The matching and non-matching sub-networks, each in the original relative order, and the cut connections
+    // as `(from, to)` identifiers of this network, sorted ascending.
+    pub fn partition(&self, pred: impl Fn(&Neuron) -> bool) -> (Network, Network, Vec<(NeuronId, NeuronId)>) {
+        let matches: Vec<bool> = self.neurons.iter().map(&pred).collect();
+        let side = |matching: bool| {
+            let members: HashSet<(usize, usize, usize)> = self.neurons.iter().zip(&matches)
+                .filter(|&(_, &m)| m == matching)
+                .map(|(neuron, _)| (neuron.x, neuron.y, neuron.z))
+                .collect();
+            let mut part = Network::new();
+            part.plasticity_enabled = self.plasticity_enabled;
+            for neuron in self.neurons.iter().filter(|neuron| members.contains(&(neuron.x, neuron.y, neuron.z))) {
+                let mut neuron = neuron.clone();
+                neuron.ac.retain(|pos| members.contains(pos));
+                neuron.dc.retain(|pos| members.contains(pos));
+                part.add_neuron(neuron);
+            }
+            part
+        };
+
+        let cut = (0..self.neurons.len())
+            .flat_map(|id| self.targets(id).into_iter().map(move |target| (id, target)))
+            .filter(|&(from, to)| matches[from] != matches[to])
+            .collect();
+        (side(true), side(false), cut)
+    }
+
+    // Removes self-loops, which can slip in through direct field edits or imported checkpoints.
+    // Returns: The number of self-loops removed; a loop recorded in both `ac` and `dc` counts once.
+    pub fn remove_self_loops(&mut self) -> usize {
+        let mut removed = 0;
+        for neuron in &mut self.neurons {
+            let pos = (neuron.x, neuron.y, neuron.z);
+            let axonal = neuron.ac.remove(&pos);
+            let dendritic = neuron.dc.remove(&pos);
+            if axonal || dendritic {
+                removed += 1;
+            }
+        }
+        removed
+    }
+
+    // Collapses neurons that share a coordinate, which can happen when positions are edited through `neuron_mut`.
+    // Each group keeps its first neuron, which takes the union of the group's connections and the average of its numeric
+    // parameters (summing firing counts); the others are removed. Later neurons shift down to fill the gaps, so their identifiers change,
+    // and the coordinate index is rebuilt. Connections are stored by coordinate, so partners stay consistent.
+    // Queued inputs, the dropout selection and recorded spikes are renumbered too; those of a removed neuron move to the
+    // neuron it was merged into.
+    // Returns: The number of neurons merged away.
+    pub fn merge_duplicate_positions(&mut self) -> usize {
+        let mut groups: HashMap<(usize, usize, usize), Vec<NeuronId>> = HashMap::new();
+        for (id, neuron) in self.neurons.iter().enumerate() {
+            groups.entry((neuron.x, neuron.y, neuron.z)).or_default().push(id);
+        }
+
+        let mut removed = HashSet::new();
+        let mut survivor: Vec<NeuronId> = (0..self.neurons.len()).collect();
+        for (pos, ids) in groups.into_iter().filter(|(_, ids)| ids.len() > 1) {
+            let mut merged = self.neurons[ids[0]].clone();
+            for &id in &ids[1..] {
+                merged.merge_connections_from(&self.neurons[id]);
+                removed.insert(id);
+                survivor[id] = ids[0];
+            }
+            // A connection between two merged neurons would become a self-connection
+            merged.ac.remove(&pos);
+            merged.dc.remove(&pos);
+            average_parameters(&mut merged, ids.iter().map(|&id| &self.neurons[id]));
+            self.neurons[ids[0]] = merged;
+        }
+
+        let mut id = 0;
+        self.neurons.retain(|_| {
+            id += 1;
+            !removed.contains(&(id - 1))
+        });
+        self.index = self.neurons.iter().enumerate().map(|(id, neuron)| ((neuron.x, neuron.y, neuron.z), id)).collect();
+
+        // Each kept neuron's new identifier is the number of kept neurons before it
+        let mut shifted = Vec::with_capacity(survivor.len());
+        let mut next = 0;
+        for id in 0..survivor.len() {
+            shifted.push(next);
+            if !removed.contains(&id) {
+                next += 1;
+            }
+        }
+        let renumber: Vec<NeuronId> = survivor.iter().map(|&id| shifted[id]).collect();
+        self.dropped = self.dropped.iter().map(|&id| renumber[id]).collect();
+        for (target, _) in self.scheduled.values_mut().flatten() {
+            *target = renumber[*target];
+        }
+        self.raster.renumber(&renumber);
+        removed.len()
+    }
+
+    // Enables or disables plasticity for every neuron, separating training from evaluation.
+    // While disabled, transmitted signals still update potentials and firing but leave all learning parameters unchanged.
+    // Parameters:
+    // - `enabled`: Whether neurons update their plasticity parameters.
+    pub fn set_plasticity_enabled(&mut self, enabled: bool) {
+        self.plasticity_enabled = enabled;
+        for neuron in &mut self.neurons {
+            neuron.plasticity_enabled = enabled;
+        }
+    }
+
+    // Applies a tonic background current to neurons without a source delay.
+    // Parameters:
+    // - `current`: The input signal value applied to each targeted neuron.
+    // - `targets`: The identifiers of the neurons to drive, or None to drive every neuron.
+    pub fn inject_current(&mut self, current: f64, targets: Option<&[NeuronId]>) {
+        match targets {
+            Some(ids) => {
+                for &id in ids {
+                    self.neurons[id].transmit_sync(current);
+                }
+            }
+            None => {
+                for neuron in &mut self.neurons {
+                    neuron.transmit_sync(current);
+                }
+            }
+        }
+    }
+
+    // Applies external inputs without a source delay to many neurons concurrently.
+    // No signal propagates between neurons within the call, so each neuron's update is independent;
+    // the result matches delivering the stimuli one by one with `transmit_sync`.
+    // Parameters:
+    // - `stimuli`: The `(neuron, input)` pairs; inputs to the same neuron are applied in order.
+    #[cfg(feature = "rayon")]
+    pub fn batch_stimulate_parallel(&mut self, stimuli: &[(NeuronId, f64)]) {
+        let mut inputs: Vec<Vec<f64>> = vec![Vec::new(); self.neurons.len()];
+        for &(id, input) in stimuli {
+            inputs[id].push(input);
+        }
+        self.neurons.par_iter_mut().zip(inputs).for_each(|(neuron, inputs)| {
+            for input in inputs {
+                neuron.transmit_sync(input);
+            }
+        });
+    }
+
+    // Queues a temporal input pattern, such as a recorded stimulation protocol, for delivery while stepping.
+    // Each input is delivered without a source delay during the tick whose clock value matches its tick, after the
+    // network's own signals, so it is detected on the following tick. Inputs for ticks already past are delivered on the next tick.
+    // Parameters:
+    // - `target`: The identifier of the neuron to stimulate.
+    // - `train`: The `(tick, input)` pairs to deliver.
+    pub fn inject_spike_train(&mut self, target: NeuronId, train: &[(u64, f64)]) {
+        for &(tick, input) in train {
+            self.scheduled.entry(tick).or_default().push((target, input));
+        }
+    }
+
+    // Applies lateral inhibition among spatial neighbors, sharpening selectivity toward winner-take-all dynamics.
+    // Every neuron above threshold lowers the accumulated potential of each other neuron within `radius` by `strength`
+    // times the magnitude of the signal it would emit; the membrane potential then follows. Firers are determined before any
+    // inhibition is applied, and neither fire nor discharge. Frozen neurons are unaffected.
+    // Parameters:
+    // - `radius`: The largest distance at which a neuron is inhibited.
+    // - `strength`: The inhibition per unit of the firer's output signal.
+    pub fn apply_lateral_inhibition(&mut self, radius: f64, strength: f64) {
+        let firers: Vec<((usize, usize, usize), f64)> = self.neurons.iter()
+            .filter(|neuron| neuron.will_fire())
+            .map(|neuron| ((neuron.x, neuron.y, neuron.z), neuron.output_signal().abs()))
+            .collect();
+
+        for neuron in self.neurons.iter_mut().filter(|neuron| !neuron.frozen) {
+            let pos = (neuron.x, neuron.y, neuron.z);
+            let inhibition: f64 = firers.iter()
+                .filter(|&&(firer, _)| firer != pos && distance(firer, pos) <= radius)
+                .map(|&(_, output)| strength * output)
+                .sum();
+            if inhibition != 0.0 {
+                neuron.ap -= inhibition;
+                neuron.update_mp(1.0);
+            }
+        }
+    }
+
+    // Applies global neuromodulation, like a neuromodulator bath, by setting every neuron's neurotransmitter concentration.
+    // Since the concentration scales how inputs accumulate, this tunes the excitability of the whole network.
+    // Parameters:
+    // - `nc`: The neurotransmitter concentration, clamped to [0, MAX_NEUROTRANSMITTER_CONCENTRATION].
+    pub fn set_global_modulation(&mut self, nc: f64) {
+        let nc = nc.clamp(0.0, Neuron::MAX_NEUROTRANSMITTER_CONCENTRATION);
+        for neuron in &mut self.neurons {
+            neuron.nc = nc;
+        }
+    }
+
+    // Applies spatially localized neuromodulation by setting the neurotransmitter concentration of the neurons in a region.
+    // Parameters:
+    // - `bounds`: The inclusive minimum and maximum corners of the region.
+    // - `nc`: The neurotransmitter concentration, clamped to [0, MAX_NEUROTRANSMITTER_CONCENTRATION].
+    pub fn modulate_region(&mut self, bounds: (impl Into<Coord3>, impl Into<Coord3>), nc: f64) {
+        let bounds = (into_tuple(bounds.0), into_tuple(bounds.1));
+        let nc = nc.clamp(0.0, Neuron::MAX_NEUROTRANSMITTER_CONCENTRATION);
+        for neuron in self.neurons.iter_mut().filter(|neuron| within_bounds((neuron.x, neuron.y, neuron.z), bounds)) {
+            neuron.nc = nc;
+        }
+    }
+
+    // Advances the network by one logical tick: every neuron above threshold fires, and each signal is delivered
+    // without a source delay to the firing neuron's axonal targets, to be detected on the next tick.
+    // Runs `prune_all` whenever the clock reaches a multiple of the pruning interval.
+    // Inputs queued by `inject_spike_train` for this tick are then delivered.
+    // Neurons selected by `apply_dropout` neither fire nor receive signals during the tick.
+    // Returns: The neurons that fired during the tick, paired with their output signal.
+    pub fn tick(&mut self) -> Vec<(NeuronId, f64)> {
+        let start = Instant::now();
+        let dropped = std::mem::take(&mut self.dropped);
+        let mut fired = Vec::new();
+        for (id, neuron) in self.neurons.iter_mut().enumerate() {
+            if dropped.contains(&id) {
+                continue;
+            }
+            let output = neuron.detect();
+            if output != 0.0 {
+                fired.push((id, output));
+                if self.recording {
+                    self.raster.record(id, self.clock);
+                }
+            } else {
+                neuron.relax_threshold();
+            }
+        }
+
+        for &(id, output) in &fired {
+            let source = &self.neurons[id];
+            let pos = (source.x, source.y, source.z);
+            for target in self.targets(id).into_iter().filter(|target| !dropped.contains(target)) {
+                self.metrics.transmits += 1;
+                if !self.neurons[target].process_signal(output, Some(pos)) {
+                    self.metrics.blocked += 1;
+                }
+            }
+        }
+
+        let pending = self.scheduled.split_off(&(self.clock + 1));
+        for (target, input) in std::mem::replace(&mut self.scheduled, pending).into_values().flatten() {
+            if !dropped.contains(&target) {
+                self.neurons[target].transmit_sync(input);
+            }
+        }
+
+        self.clock += 1;
+        if self.pruning_interval > 0 && self.clock.is_multiple_of(self.pruning_interval) {
+            self.prune_all();
+        }
+
+        self.metrics.ticks += 1;
+        self.metrics.fires += fired.len() as u64;
+        self.metrics.elapsed += start.elapsed();
+        fired
+    }
+
+    // Records every neuron's synaptic weight as a baseline for `weight_delta_since`.
+    // Returns: The snapshot of the current weights.
+    pub fn snapshot_weights(&self) -> WeightSnapshot {
+        WeightSnapshot {
+            weights: self.neurons.iter().map(|neuron| ((neuron.x, neuron.y, neuron.z), neuron.sw)).collect(),
+        }
+    }
+
+    // Measures how far learning has moved the synaptic weights since a snapshot, as a convergence monitor.
+    // Neurons are matched by coordinate; neurons added or removed since the snapshot are ignored.
+    // Parameters:
+    // - `snapshot`: The baseline taken by `snapshot_weights`.
+    // Returns: The total absolute change in `sw` across the neurons present in both.
+    pub fn weight_delta_since(&self, snapshot: &WeightSnapshot) -> f64 {
+        self.neurons.iter()
+            .filter_map(|neuron| snapshot.weights.get(&(neuron.x, neuron.y, neuron.z)).map(|sw| (neuron.sw - sw).abs()))
+            .sum()
+    }
+
+    // Drives the network with external input given as a function of time, such as a sinusoid or a ramp.
+    // Before each tick the inputs returned for the current clock value are delivered without a source delay,
+    // except to neurons silenced by `apply_dropout` for that tick.
+    // Parameters:
+    // - `ticks`: The number of ticks to run.
+    // - `input_fn`: Maps the clock value of a tick to the input of each driven neuron.
+    // Returns: The firing of every tick, in order.
+    pub fn run_with_input(&mut self, ticks: u64, input_fn: impl Fn(u64) -> HashMap<NeuronId, f64>) -> Vec<FiringSnapshot> {
+        (0..ticks).map(|_| {
+            let tick = self.clock;
+            for (id, input) in input_fn(tick).into_iter().filter(|(id, _)| !self.dropped.contains(id)) {
+                self.neurons[id].transmit_sync(input);
+            }
+            FiringSnapshot { tick, fired: self.tick() }
+        }).collect()
+    }
+
+    // Returns: The throughput counters collected by `tick`.
+    pub fn metrics(&self) -> &Metrics {
+        &self.metrics
+    }
+
+    // Clears the throughput counters.
+    pub fn reset_metrics(&mut self) {
+        self.metrics = Metrics::default();
+    }
+
+    // Simulates a single wave of activity started by stimulating one neuron.
+    // A neuron that fires at tick `t` delivers its signal to each target at `t` plus the distance delay in ticks
+    // (the `transmit` delay in milliseconds, one tick per millisecond, at least one tick). Each neuron fires at most once
+    // per wave, and signals are processed in arrival order. Ticks are counted from the network clock, which is not advanced.
+    // Parameters:
+    // - `source`: The identifier of the neuron to stimulate.
+    // - `stimulus`: The input signal value applied to the source.
+    // Returns: Each neuron that fired paired with its firing tick, in firing order.
+    pub fn fire_wave(&mut self, source: NeuronId, stimulus: f64) -> Vec<(NeuronId, u64)> {
+        let mut activations = Vec::new();
+        let mut fired = HashSet::new();
+        let mut arrivals: BinaryHeap<Reverse<(u64, NeuronId, Option<NeuronId>)>> = BinaryHeap::new();
+        let mut signals: HashMap<(NeuronId, NeuronId), f64> = HashMap::new();
+        arrivals.push(Reverse((self.clock, source, None)));
+
+        while let Some(Reverse((tick, id, from))) = arrivals.pop() {
+            if fired.contains(&id) {
+                continue;
+            }
+            let input = from.map_or(stimulus, |from| signals[&(from, id)]);
+            let pos = from.map(|from| {
+                let neuron = &self.neurons[from];
+                (neuron.x, neuron.y, neuron.z)
+            });
+            self.neurons[id].process_signal(input, pos);
+            let output = self.neurons[id].detect();
+            if output == 0.0 {
+                continue;
+            }
+
+            fired.insert(id);
+            activations.push((id, tick));
+            for target in self.targets(id) {
+                let delay = self.neurons[target].delay_duration(&self.neurons[id]).map_or(0, |delay| delay.as_millis() as u64);
+                signals.insert((id, target), output);
+                arrivals.push(Reverse((tick + delay.max(1), target, Some(id))));
+            }
+        }
+
+        activations
+    }
+
+    // Propagates activity from one stimulated neuron under a global energy budget, so it terminates even through cycles.
+    // Signals are delivered breadth-first without source delays; a neuron may fire again whenever a signal brings it to threshold.
+    // Each delivered signal costs its magnitude, and propagation halts at the first signal the remaining budget cannot pay for.
+    // The network clock is not advanced.
+    // Parameters:
+    // - `source`: The identifier of the neuron to stimulate.
+    // - `input`: The input signal value applied to the source.
+    // - `energy_budget`: The total signal magnitude that may be delivered.
+    // Returns: Each firing, as the neuron paired with its output signal, in firing order.
+    pub fn propagate_bounded(&mut self, source: NeuronId, input: f64, energy_budget: f64) -> Vec<(NeuronId, f64)> {
+        let mut activations = Vec::new();
+        let mut budget = energy_budget;
+        let mut queue = VecDeque::from([(source, input, None)]);
+
+        while let Some((id, signal, from)) = queue.pop_front() {
+            self.neurons[id].process_signal(signal, from);
+            let output = self.neurons[id].detect();
+            if output == 0.0 {
+                continue;
+            }
+
+            activations.push((id, output));
+            let neuron = &self.neurons[id];
+            let pos = (neuron.x, neuron.y, neuron.z);
+            for target in self.targets(id) {
+                if output.abs() > budget {
+                    return activations;
+                }
+                budget -= output.abs();
+                queue.push_back((target, output, Some(pos)));
+            }
+        }
+
+        activations
+    }
+
+    // Returns: The number of ticks the network has advanced.
+    pub fn clock(&self) -> u64 {
+        self.clock
+    }
+
+    // Enables or disables spike recording in `tick`. Previously recorded spikes are kept.
+    // Parameters:
+    // - `enabled`: True to record every fire as `(neuron id, tick)` into the spike raster.
+    pub fn set_recording(&mut self, enabled: bool) {
+        self.recording = enabled;
+    }
+
+    // Returns: The spikes recorded so far.
+    pub fn spike_raster(&self) -> &SpikeRaster {
+        &self.raster
+    }
+
+    // Removes every recorded spike.
+    pub fn clear_spike_raster(&mut self) {
+        self.raster.clear();
+    }
+
+    // Sets how often `tick` prunes connections automatically.
+    // Parameters:
+    // - `ticks`: The number of ticks between pruning passes; 0 disables automatic pruning.
+    pub fn set_pruning_interval(&mut self, ticks: u64) {
+        self.pruning_interval = ticks;
+    }
+
+    // Prunes every axonal connection in the network based on synaptic strength.
+    // Returns: The number of connections removed.
+    pub fn prune_all(&mut self) -> usize {
+        let edges: Vec<(NeuronId, NeuronId)> = (0..self.neurons.len())
+            .flat_map(|from| self.targets(from).into_iter().map(move |to| (from, to)))
+            .collect();
+
+        let mut removed = 0;
+        for (from, to) in edges {
+            let (source, target) = self.pair_mut(from, to);
+            source.prune_axonal_connection(target);
+            if !source.ac.contains(&(target.x, target.y, target.z)) {
+                removed += 1;
+            }
+        }
+        removed
+    }
+
+    // Removes every axonal connection of the neurons that have rarely fired, cleaning up wiring that never carried a signal.
+    // Parameters:
+    // - `min_fires`: The fire count (`Neuron::fire_count`) a neuron needs to keep its outgoing connections.
+    // Returns: The number of connections removed.
+    pub fn prune_inactive(&mut self, min_fires: u64) -> usize {
+        let mut removed = 0;
+        for from in 0..self.neurons.len() {
+            if self.neurons[from].fire_count >= min_fires {
+                continue;
+            }
+            for to in self.targets(from) {
+                self.disconnect(from, to);
+                removed += 1;
+            }
+        }
+        removed
+    }
+
+    // Removes a fixed fraction of the weakest connections network-wide, for predictable sparsification.
+    // Connections are ranked by the magnitude of their effective weight; ties are broken by identifier order.
+    // Parameters:
+    // - `fraction`: The fraction of connections to remove, clamped to [0, 1] and rounded down to a whole count.
+    // Returns: The number of connections removed.
+    pub fn prune_weakest_fraction(&mut self, fraction: f64) -> usize {
+        let mut edges: Vec<(NeuronId, NeuronId, f64)> = (0..self.neurons.len())
+            .flat_map(|from| {
+                let weight = signed_weight(&self.neurons[from]).abs();
+                self.targets(from).into_iter().map(move |to| (from, to, weight))
+            })
+            .collect();
+        edges.sort_by(|a, b| a.2.total_cmp(&b.2));
+
+        let count = (fraction.clamp(0.0, 1.0) * edges.len() as f64).floor() as usize;
+        for &(from, to, _) in &edges[..count] {
+            self.disconnect(from, to);
+        }
+        count
+    }
+
+    // Returns: The number of neurons in the network.
+    pub fn len(&self) -> usize {
+        self.neurons.len()
+    }
+
+    // Returns: True if the network contains no neurons.
+    pub fn is_empty(&self) -> bool {
+        self.neurons.is_empty()
+    }
+
+    // Returns: All neurons in identifier order.
+    pub fn neurons(&self) -> &[Neuron] {
+        &self.neurons
+    }
+
+    // Parameters:
+    // - `id`: The identifier of the neuron.
+    // Returns: A reference to the neuron.
+    pub fn neuron(&self, id: NeuronId) -> &Neuron {
+        &self.neurons[id]
+    }
+
+    // Parameters:
+    // - `id`: The identifier of the neuron.
+    // Returns: A mutable reference to the neuron. Its coordinate must not be changed.
+    pub fn neuron_mut(&mut self, id: NeuronId) -> &mut Neuron {
+        &mut self.neurons[id]
+    }
+
+    // Looks up a neuron by its coordinate.
+    // Parameters:
+    // - `pos`: The neuron coordinate.
+    // Returns: The identifier of the neuron at that coordinate, if any.
+    pub fn id_of(&self, pos: impl Into<Coord3>) -> Option<NeuronId> {
+        self.index.get(&into_tuple(pos)).copied()
+    }
+
+    // Establishes an axonal connection from one neuron to another.
+    // Parameters:
+    // - `from`: The identifier of the axonal (presynaptic) neuron.
+    // - `to`: The identifier of the dendritic (postsynaptic) neuron.
+    // Returns: `NeuronError::FanOutExceeded` if the axonal neuron has reached its `params.max_fan_out`.
+    pub fn connect(&mut self, from: NeuronId, to: NeuronId) -> Result<(), NeuronError> {
+        let (source, target) = self.pair_mut(from, to);
+        source.establish_axonal_connection(target)
+    }
+
+    // Terminates an axonal connection from one neuron to another.
+    // Parameters:
+    // - `from`: The identifier of the axonal (presynaptic) neuron.
+    // - `to`: The identifier of the dendritic (postsynaptic) neuron.
+    pub fn disconnect(&mut self, from: NeuronId, to: NeuronId) {
+        let (source, target) = self.pair_mut(from, to);
+        source.terminate_axonal_connection(target);
+    }
+
+    // Lists the neurons reachable through a neuron's axonal connections.
+    // Parameters:
+    // - `id`: The identifier of the neuron.
+    // Returns: The identifiers of the connected neurons, sorted ascending. Connections to coordinates outside the network are skipped.
+    pub fn targets(&self, id: NeuronId) -> Vec<NeuronId> {
+        let mut targets: Vec<NeuronId> = self.neurons[id].ac.iter().filter_map(|pos| self.id_of(*pos)).collect();
+        targets.sort_unstable();
+        targets
+    }
+
+    // Parameters:
+    // - `id`: The identifier of the neuron.
+    // Returns: The neuron's `(out_degree, in_degree)`, taken from its axonal and dendritic connection counts.
+    pub fn degree(&self, id: NeuronId) -> (usize, usize) {
+        let neuron = &self.neurons[id];
+        (neuron.ac.len(), neuron.dc.len())
+    }
+
+    // Returns: The identifiers of neurons with no axonal or dendritic connections, which usually indicates a wiring mistake.
+    pub fn isolated(&self) -> Vec<NeuronId> {
+        (0..self.neurons.len()).filter(|&id| self.degree(id) == (0, 0)).collect()
+    }
+
+    // Measures how fragmented the network is, treating connections as undirected.
+    // Connections to coordinates outside the network are ignored.
+    // Returns: The neuron count of every connected component, largest first.
+    pub fn component_sizes(&self) -> Vec<usize> {
+        let mut visited = vec![false; self.neurons.len()];
+        let mut sizes = Vec::new();
+        for start in 0..self.neurons.len() {
+            if visited[start] {
+                continue;
+            }
+            visited[start] = true;
+            let mut stack = vec![start];
+            let mut size = 0;
+            while let Some(id) = stack.pop() {
+                size += 1;
+                let neuron = &self.neurons[id];
+                for neighbor in neuron.ac.iter().chain(&neuron.dc).filter_map(|&pos| self.id_of(pos)) {
+                    if !visited[neighbor] {
+                        visited[neighbor] = true;
+                        stack.push(neighbor);
+                    }
+                }
+            }
+            sizes.push(size);
+        }
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        sizes
+    }
+
+    // Returns: The fraction of neurons in the largest connected component, from `component_sizes`; 0.0 for an empty network.
+    pub fn largest_component_fraction(&self) -> f64 {
+        match self.component_sizes().first() {
+            Some(&largest) => largest as f64 / self.neurons.len() as f64,
+            None => 0.0,
+        }
+    }
+
+    // Polls which neurons are above threshold between ticks, for live dashboards, without firing them.
+    // Returns: Each neuron's `will_fire` state, keyed by identifier.
+    pub fn firing_snapshot(&self) -> HashMap<NeuronId, bool> {
+        self.neurons.iter().map(Neuron::will_fire).enumerate().collect()
+    }
+
+    // Buckets the neurons' firing rates into equal-width bins over [0, MAX_FIRING_RATE], for a quick population-activity readout.
+    // Parameters:
+    // - `bins`: The number of bins.
+    // Returns: The neuron count of each bin, lowest rates first; a rate at the upper bound falls in the last bin.
+    pub fn firing_rate_histogram(&self, bins: usize) -> Vec<usize> {
+        let mut histogram = vec![0; bins];
+        if bins == 0 {
+            return histogram;
+        }
+        for neuron in &self.neurons {
+            let fraction = (neuron.fr / Neuron::MAX_FIRING_RATE).clamp(0.0, 1.0);
+            histogram[((fraction * bins as f64) as usize).min(bins - 1)] += 1;
+        }
+        histogram
+    }
+
+    // Returns: The mean firing rate over all neurons, or 0.0 for an empty network.
+    pub fn mean_firing_rate(&self) -> f64 {
+        if self.neurons.is_empty() {
+            return 0.0;
+        }
+        self.neurons.iter().map(|neuron| neuron.fr).sum::<f64>() / self.neurons.len() as f64
+    }
+
+    // Parameters:
+    // - `from`: The identifier of the axonal (presynaptic) neuron.
+    // - `to`: The identifier of the dendritic (postsynaptic) neuron.
+    // Returns: The effective weight of the connection, the presynaptic synaptic weight negated for inhibitory neurons, or None if not connected.
+    pub fn edge_weight(&self, from: NeuronId, to: NeuronId) -> Option<f64> {
+        let source = &self.neurons[from];
+        let target = &self.neurons[to];
+        source.ac.contains(&(target.x, target.y, target.z)).then(|| signed_weight(source))
+    }
+
+    // Ranks neurons by a centrality score combining their connectivity and synaptic weight.
+    // The score is `out_degree + in_degree + |sw|`, so degree dominates and the weight breaks ties between equally connected neurons.
+    // Parameters:
+    // - `top_n`: The maximum number of neurons to return.
+    // Returns: Up to `top_n` neurons paired with their score, sorted by descending score, ties broken by identifier.
+    pub fn detect_hubs(&self, top_n: usize) -> Vec<(NeuronId, f64)> {
+        let mut scores: Vec<(NeuronId, f64)> = self.neurons.iter().enumerate()
+            .map(|(id, neuron)| (id, (neuron.ac.len() + neuron.dc.len()) as f64 + neuron.sw.abs()))
+            .collect();
+        scores.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+        scores.truncate(top_n);
+        scores
+    }
+
+    // Performs a breadth-first traversal along axonal connections.
+    // Parameters:
+    // - `source`: The identifier of the neuron to start from.
+    // Returns: The reachable neurons grouped by hop distance; level 0 holds only `source`, and each level is sorted ascending.
+    pub fn bfs_from(&self, source: NeuronId) -> Vec<Vec<NeuronId>> {
+        let mut visited = HashSet::from([source]);
+        let mut levels = vec![vec![source]];
+
+        loop {
+            let mut next: Vec<NeuronId> = Vec::new();
+            for &id in levels.last().unwrap() {
+                for target in self.targets(id) {
+                    if visited.insert(target) {
+                        next.push(target);
+                    }
+                }
+            }
+            if next.is_empty() {
+                break;
+            }
+            next.sort_unstable();
+            levels.push(next);
+        }
+
+        levels
+    }
+
+    // Calculates the mean shortest hop count along axonal connections, a small-world characteristic of the network.
+    // Pairs with no connecting path are ignored, so a disconnected network is measured over its reachable pairs.
+    // Returns: The mean over every ordered pair of distinct neurons where the second is reachable from the first,
+    // or `None` if no such pair exists.
+    pub fn average_path_length(&self) -> Option<f64> {
+        let (total, pairs) = self.shortest_path_lengths().fold((0, 0), |(total, pairs), hops| (total + hops, pairs + 1));
+        (pairs > 0).then(|| total as f64 / pairs as f64)
+    }
+
+    // Calculates the diameter of the network along axonal connections.
+    // Pairs with no connecting path are ignored, as in `average_path_length`.
+    // Returns: The longest shortest hop count over every reachable ordered pair of distinct neurons, or `None` if no such pair exists.
+    pub fn diameter(&self) -> Option<usize> {
+        self.shortest_path_lengths().max()
+    }
+
+    // Returns: The shortest hop count of every reachable ordered pair of distinct neurons, found by a breadth-first search from each neuron.
+    fn shortest_path_lengths(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.neurons.len()).flat_map(|source| {
+            self.bfs_from(source).into_iter().enumerate().skip(1).flat_map(|(hops, level)| std::iter::repeat_n(hops, level.len()))
+        })
+    }
+
+    // Collects every neuron reachable along axonal connections from any of several sources, by a multi-source breadth-first search.
+    // Neurons outside the set are regions the sources cannot influence.
+    // Parameters:
+    // - `sources`: The identifiers of the neurons to start from.
+    // Returns: The reachable neurons, including the sources themselves.
+    pub fn reachable_from(&self, sources: &[NeuronId]) -> HashSet<NeuronId> {
+        let mut reached: HashSet<NeuronId> = sources.iter().copied().collect();
+        let mut queue: VecDeque<NeuronId> = reached.iter().copied().collect();
+        while let Some(id) = queue.pop_front() {
+            for target in self.targets(id) {
+                if reached.insert(target) {
+                    queue.push_back(target);
+                }
+            }
+        }
+        reached
+    }
+
+    // Enumerates every simple (non-repeating) path along axonal connections between two neurons, by a bounded depth-first search.
+    // Parameters:
+    // - `from`: The identifier of the first neuron of each path.
+    // - `to`: The identifier of the last neuron of each path.
+    // - `max_len`: The largest number of connections a path may have, which keeps the search from exploding.
+    // Returns: The paths as neuron identifier sequences from `from` to `to`, in lexicographic order.
+    pub fn all_simple_paths(&self, from: NeuronId, to: NeuronId, max_len: usize) -> Vec<Vec<NeuronId>> {
+        let mut paths = Vec::new();
+        let mut path = vec![from];
+        let mut on_path = HashSet::from([from]);
+        self.extend_simple_paths(to, max_len, &mut path, &mut on_path, &mut paths);
+        paths
+    }
+
+    // Extends the current path by each unvisited target, recording it whenever it reaches the destination.
+    // Parameters:
+    // - `to`: The identifier of the destination neuron.
+    // - `max_len`: The largest number of connections a path may have.
+    // - `path`: The path under construction.
+    // - `on_path`: The neurons on the current path.
+    // - `paths`: The completed paths.
+    fn extend_simple_paths(&self, to: NeuronId, max_len: usize, path: &mut Vec<NeuronId>, on_path: &mut HashSet<NeuronId>, paths: &mut Vec<Vec<NeuronId>>) {
+        let last = *path.last().unwrap();
+        if last == to {
+            paths.push(path.clone());
+            return;
+        }
+        if path.len() > max_len {
+            return;
+        }
+        for target in self.targets(last) {
+            if on_path.insert(target) {
+                path.push(target);
+                self.extend_simple_paths(to, max_len, path, on_path, paths);
+                path.pop();
+                on_path.remove(&target);
+            }
+        }
+    }
+
+    // Sorts the neurons so that every neuron comes after all neurons connected to its dendrites.
+    // Ties are broken by the smaller identifier, so the order is deterministic.
+    // Returns: The neuron identifiers in topological order over axonal connections,
+    // or a `CycleError` naming a neuron on a cycle if the network is not acyclic.
+    pub fn topological_order(&self) -> Result<Vec<NeuronId>, CycleError> {
+        let targets: Vec<Vec<NeuronId>> = (0..self.neurons.len()).map(|id| self.targets(id)).collect();
+        let mut in_degree = vec![0; self.neurons.len()];
+        for &target in targets.iter().flatten() {
+            in_degree[target] += 1;
+        }
+
+        let mut ready: BinaryHeap<Reverse<NeuronId>> = (0..self.neurons.len()).filter(|&id| in_degree[id] == 0).map(Reverse).collect();
+        let mut order = Vec::with_capacity(self.neurons.len());
+        while let Some(Reverse(id)) = ready.pop() {
+            order.push(id);
+            for &target in &targets[id] {
+                in_degree[target] -= 1;
+                if in_degree[target] == 0 {
+                    ready.push(Reverse(target));
+                }
+            }
+        }
+
+        if order.len() == self.neurons.len() {
+            return Ok(order);
+        }
+
+        // Every unsorted neuron has an unsorted predecessor; walking predecessors must revisit a neuron on the cycle
+        let mut predecessors = vec![None; self.neurons.len()];
+        for (id, ids) in targets.iter().enumerate() {
+            for &target in ids {
+                if in_degree[id] > 0 && in_degree[target] > 0 {
+                    predecessors[target].get_or_insert(id);
+                }
+            }
+        }
+        let mut node = (0..self.neurons.len()).find(|&id| in_degree[id] > 0).unwrap();
+        let mut visited = HashSet::new();
+        while visited.insert(node) {
+            node = predecessors[node].unwrap();
+        }
+        Err(CycleError { node })
+    }
+
+    // Evaluates an acyclic network in a single synchronous pass.
+    // Neurons are visited in topological order; each one receives the sum of its external input and the signals of its
+    // already-evaluated predecessors as one `transmit_sync` call, then is detected. Neurons without input are only detected.
+    // Parameters:
+    // - `inputs`: External input signals by neuron identifier.
+    // Returns: The output signal of every neuron ( 0.0 if it did not fire ), or a `CycleError` if the network has a cycle,
+    // in which case no neuron is changed.
+    pub fn evaluate_feedforward(&mut self, inputs: &HashMap<NeuronId, f64>) -> Result<HashMap<NeuronId, f64>, CycleError> {
+        let order = self.topological_order()?;
+        let mut received: Vec<f64> = (0..self.neurons.len()).map(|id| inputs.get(&id).copied().unwrap_or(0.0)).collect();
+        let mut outputs = HashMap::with_capacity(self.neurons.len());
+        for id in order {
+            if received[id] != 0.0 {
+                self.neurons[id].process_signal(received[id], None);
+            }
+            let output = self.neurons[id].detect();
+            if output != 0.0 {
+                for target in self.targets(id) {
+                    received[target] += output;
+                }
+            }
+            outputs.insert(id, output);
+        }
+
+        Ok(outputs)
+    }
+
+    // Finds the neurons closest to a coordinate.
+    // Parameters:
+    // - `center`: The coordinate to measure from.
+    // - `k`: The maximum number of neurons to return.
+    // Returns: Up to `k` neurons paired with their distance to `center`, sorted by ascending distance, ties broken by identifier.
+    pub fn k_nearest(&self, center: impl Into<Coord3>, k: usize) -> Vec<(NeuronId, f64)> {
+        let center = into_tuple(center);
+        let mut nearest: Vec<(NeuronId, f64)> = self.neurons.iter().enumerate()
+            .map(|(id, neuron)| (id, distance(center, (neuron.x, neuron.y, neuron.z))))
+            .collect();
+        nearest.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+        nearest.truncate(k);
+        nearest
+    }
+
+    // Connects every neuron's axon to its k nearest neighbors, excluding itself.
+    // The resulting graph may be asymmetric: B being among A's nearest neighbors does not imply the reverse.
+    // A neuron stops gaining connections once it reaches its fan-out limit.
+    // Parameters:
+    // - `k`: The number of neighbors each neuron connects to.
+    pub fn connect_k_nearest(&mut self, k: usize) {
+        for id in 0..self.neurons.len() {
+            let neuron = &self.neurons[id];
+            let neighbors: Vec<NeuronId> = self.k_nearest((neuron.x, neuron.y, neuron.z), k + 1).into_iter()
+                .map(|(neighbor, _)| neighbor)
+                .filter(|&neighbor| neighbor != id)
+                .take(k)
+                .collect();
+            for neighbor in neighbors {
+                if self.connect(id, neighbor).is_err() {
+                    break; // Fan-out limit reached
+                }
+            }
+        }
+    }
+
+    // Connects every ordered pair of distinct neurons that satisfies a predicate, a general primitive for radius, type or distance wiring.
+    // The predicate is evaluated on the network as it was before the call. A neuron stops gaining connections once it reaches its fan-out limit.
+    // Parameters:
+    // - `pred`: Decides whether the first neuron's axon connects to the second neuron.
+    pub fn connect_if(&mut self, pred: impl Fn(&Neuron, &Neuron) -> bool) {
+        let pairs: Vec<(NeuronId, Vec<NeuronId>)> = self.neurons.iter().enumerate()
+            .map(|(from, source)| {
+                let targets = self.neurons.iter().enumerate()
+                    .filter(|&(to, target)| to != from && pred(source, target))
+                    .map(|(to, _)| to)
+                    .collect();
+                (from, targets)
+            })
+            .collect();
+        for (from, targets) in pairs {
+            for to in targets {
+                if self.connect(from, to).is_err() {
+                    break; // Fan-out limit reached
+                }
+            }
+        }
+    }
+
+    // Finds the path whose weakest connection is as strong as possible, using a bottleneck variant of Dijkstra's algorithm.
+    // The strength of a connection is the magnitude of the presynaptic neuron's synaptic weight.
+    // Parameters:
+    // - `from`: The identifier of the neuron to start from.
+    // - `to`: The identifier of the neuron to reach.
+    // Returns: The path including both endpoints and its bottleneck strength, or None if `to` is unreachable. A path from a neuron to itself has infinite strength.
+    pub fn strongest_path(&self, from: NeuronId, to: NeuronId) -> Option<(Vec<NeuronId>, f64)> {
+        let mut best = vec![f64::NEG_INFINITY; self.neurons.len()];
+        let mut previous: Vec<Option<NeuronId>> = vec![None; self.neurons.len()];
+        let mut heap = BinaryHeap::from([PathCandidate { strength: f64::INFINITY, id: from }]);
+        best[from] = f64::INFINITY;
+
+        while let Some(PathCandidate { strength, id }) = heap.pop() {
+            if id == to {
+                let mut path = vec![to];
+                while let Some(prev) = previous[*path.last().unwrap()] {
+                    path.push(prev);
+                }
+                path.reverse();
+                return Some((path, strength));
+            }
+            if strength < best[id] {
+                continue; // Stale heap entry
+            }
+            let edge = self.neurons[id].sw.abs();
+            for target in self.targets(id) {
+                let candidate = strength.min(edge);
+                if candidate > best[target] {
+                    best[target] = candidate;
+                    previous[target] = Some(id);
+                    heap.push(PathCandidate { strength: candidate, id: target });
+                }
+            }
+        }
+
+        None
+    }
+
+    // Multiplies the signed synaptic weights along a route through the network: each hop contributes the presynaptic
+    // neuron's `sw`, negated for inhibitory neurons. This is a structural measure of the route, not a simulated transfer gain;
+    // it ignores thresholds, firing rates and neurotransmitter concentration.
+    // Parameters:
+    // - `path`: The identifiers of the neurons along the route, in order.
+    // Returns: The product of the signed weights; 0.0 if any hop lacks an axonal connection, 1.0 for a path with no hops.
+    pub fn path_weight(&self, path: &[NeuronId]) -> f64 {
+        path.windows(2).map(|hop| {
+            let source = &self.neurons[hop[0]];
+            let target = &self.neurons[hop[1]];
+            if !source.ac.contains(&(target.x, target.y, target.z)) {
+                return 0.0;
+            }
+            signed_weight(source)
+        }).product()
+    }
+
+    // Exports the network in Graphviz DOT format.
+    // Neurons and connections are emitted in lexicographic coordinate order, so the output is byte-stable for the same topology.
+    // Returns: The DOT source, labelling each edge with its signed synaptic weight. Connections to coordinates outside the network are dropped.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph network {\n");
+        for neuron in self.sorted_neurons() {
+            dot.push_str(&format!("    \"{},{},{}\" [nt={}, nrt={}];\n", neuron.x, neuron.y, neuron.z, neuron.nt, neuron.nrt));
+        }
+        for (from, to, weight) in self.sorted_edges() {
+            dot.push_str(&format!("    \"{},{},{}\" -> \"{},{},{}\" [weight={}];\n", from.0, from.1, from.2, to.0, to.1, to.2, weight));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    // Exports the network's connections as CSV with a header row.
+    // Connections are emitted in lexicographic coordinate order, so the output is byte-stable for the same topology.
+    // Returns: One `from_x,from_y,from_z,to_x,to_y,to_z,weight` row per connection. Connections to coordinates outside the network are dropped.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("from_x,from_y,from_z,to_x,to_y,to_z,weight\n");
+        for (from, to, weight) in self.sorted_edges() {
+            csv.push_str(&format!("{},{},{},{},{},{},{}\n", from.0, from.1, from.2, to.0, to.1, to.2, weight));
+        }
+        csv
+    }
+
+    // Exports the network as GraphML for graph analysis tools such as Gephi or Cytoscape.
+    // Returns: The GraphML document with node attributes (position, type, neurotransmitter, firing rate, synaptic weight)
+    // and edge attributes (weight, sign). Connections to coordinates outside the network are dropped.
+    pub fn to_graphml(&self) -> String {
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str("<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n");
+        for (key, domain, name, kind) in [
+            ("x", "node", "x", "int"),
+            ("y", "node", "y", "int"),
+            ("z", "node", "z", "int"),
+            ("nt", "node", "type", "int"),
+            ("nrt", "node", "neurotransmitter", "int"),
+            ("fr", "node", "firing_rate", "double"),
+            ("sw", "node", "synaptic_weight", "double"),
+            ("weight", "edge", "weight", "double"),
+            ("sign", "edge", "sign", "int"),
+        ] {
+            xml.push_str(&format!("  <key id=\"{}\" for=\"{}\" attr.name=\"{}\" attr.type=\"{}\"/>\n", key, domain, name, kind));
+        }
+        xml.push_str("  <graph id=\"network\" edgedefault=\"directed\">\n");
+
+        for neuron in self.sorted_neurons() {
+            let id = self.index[&(neuron.x, neuron.y, neuron.z)];
+            xml.push_str(&format!("    <node id=\"n{}\">\n", id));
+            for (key, value) in [
+                ("x", neuron.x.to_string()),
+                ("y", neuron.y.to_string()),
+                ("z", neuron.z.to_string()),
+                ("nt", neuron.nt.to_string()),
+                ("nrt", neuron.nrt.to_string()),
+                ("fr", neuron.fr.to_string()),
+                ("sw", neuron.sw.to_string()),
+            ] {
+                xml.push_str(&format!("      <data key=\"{}\">{}</data>\n", key, value));
+            }
+            xml.push_str("    </node>\n");
+        }
+
+        for neuron in self.sorted_neurons() {
+            let from = self.index[&(neuron.x, neuron.y, neuron.z)];
+            let sign = if neuron.nrt == 1 { 1 } else { -1 };
+            for to in neuron.axonal_connections().iter().filter_map(|pos| self.id_of(*pos)) {
+                xml.push_str(&format!("    <edge source=\"n{}\" target=\"n{}\">\n", from, to));
+                xml.push_str(&format!("      <data key=\"weight\">{}</data>\n", neuron.sw));
+                xml.push_str(&format!("      <data key=\"sign\">{}</data>\n", sign));
+                xml.push_str("    </edge>\n");
+            }
+        }
+
+        xml.push_str("  </graph>\n</graphml>\n");
+        xml
+    }
+
+    // Computes a stable hash of the network's structure and weights, for comparing networks and verifying reproducibility.
+    // Neurons are hashed in coordinate order with their connections sorted, so the result is independent of insertion order
+    // and hash set iteration order. Weights are rounded to `FINGERPRINT_WEIGHT_PRECISION` to absorb floating point noise.
+    // Returns: The 64-bit FNV-1a hash of every neuron's position, type, neurotransmitter type, rounded weight and axonal connections.
+    pub fn fingerprint(&self) -> u64 {
+        let mut hash = FNV_OFFSET_BASIS;
+        for neuron in self.sorted_neurons() {
+            let connections = neuron.axonal_connections();
+            let weight = (neuron.sw / FINGERPRINT_WEIGHT_PRECISION).round() as i64;
+            let fields = [neuron.x as u64, neuron.y as u64, neuron.z as u64, u64::from(neuron.nt), u64::from(neuron.nrt), weight as u64, connections.len() as u64];
+            let coords = connections.into_iter().flat_map(|(x, y, z)| [x as u64, y as u64, z as u64]);
+            for value in fields.into_iter().chain(coords) {
+                hash = fnv1a(hash, value);
+            }
+        }
+        hash
+    }
+
+    // Returns: All neurons sorted by coordinate.
+    fn sorted_neurons(&self) -> Vec<&Neuron> {
+        let mut neurons: Vec<&Neuron> = self.neurons.iter().collect();
+        neurons.sort_unstable_by_key(|neuron| (neuron.x, neuron.y, neuron.z));
+        neurons
+    }
+
+    // Returns: Every connection between neurons of the network as `(from, to, signed weight)`, sorted by coordinates.
+    fn sorted_edges(&self) -> Vec<Edge> {
+        let mut edges = Vec::new();
+        for neuron in self.sorted_neurons() {
+            let weight = signed_weight(neuron);
+            for to in neuron.axonal_connections().into_iter().filter(|pos| self.index.contains_key(pos)) {
+                edges.push(((neuron.x, neuron.y, neuron.z), to, weight));
+            }
+        }
+        edges
+    }
+
+    // Selects a random fraction of neurons to silence during the next tick, the network analog of dropout.
+    // Each neuron is dropped independently with probability `rate`; the selection replaces any previous one.
+    // Parameters:
+    // - `rate`: The probability of dropping each neuron, clamped to [0, 1].
+    // - `rng`: The random number generator drawing the selection.
+    // Returns: The identifiers of the dropped neurons.
+    #[cfg(feature = "rand")]
+    pub fn apply_dropout(&mut self, rate: f64, rng: &mut impl Rng) -> HashSet<NeuronId> {
+        let rate = rate.clamp(0.0, 1.0);
+        self.dropped = (0..self.neurons.len()).filter(|_| rng.random_bool(rate)).collect();
+        self.dropped.clone()
+    }
+
+    // Moves every neuron by a random offset of up to `max_offset` along each axis, shifting its axon coordinate alongside.
+    // A move onto an occupied coordinate is rejected and the neuron stays in place. Connections and the coordinate index are updated.
+    // Parameters:
+    // - `max_offset`: The largest offset applied along any axis.
+    // - `rng`: The random number generator drawing the offsets.
+    #[cfg(feature = "rand")]
+    pub fn jitter_positions(&mut self, max_offset: usize, rng: &mut impl Rng) {
+        let max_offset = max_offset as i64;
+        for id in 0..self.neurons.len() {
+            let offset: [isize; 3] = std::array::from_fn(|_| rng.random_range(-max_offset..=max_offset) as isize);
+            let neuron = &self.neurons[id];
+            let pos = (
+                neuron.x.saturating_add_signed(offset[0]),
+                neuron.y.saturating_add_signed(offset[1]),
+                neuron.z.saturating_add_signed(offset[2]),
+            );
+            if self.index.contains_key(&pos) {
+                continue; // Rejected: the target coordinate is occupied (or unchanged)
+            }
+
+            self.relocate(id, pos);
+            let neuron = &mut self.neurons[id];
+            neuron.ax = neuron.ax.saturating_add_signed(offset[0]);
+            neuron.ay = neuron.ay.saturating_add_signed(offset[1]);
+            neuron.az = neuron.az.saturating_add_signed(offset[2]);
+        }
+    }
+
+    // Connects a neuron's axon to randomly chosen targets, favoring nearby neurons.
+    // Each target is drawn without replacement with probability inversely proportional to its distance from the source;
+    // a candidate co-located with the source is weighted as if it were one unit away.
+    // Neurons the source is already connected to are not considered.
+    // Parameters:
+    // - `source`: The identifier of the presynaptic neuron.
+    // - `n`: The number of targets to connect; fewer are connected if not enough candidates exist or the fan-out limit is reached.
+    // - `rng`: The random number generator drawing the targets.
+    #[cfg(feature = "rand")]
+    pub fn connect_distance_weighted(&mut self, source: NeuronId, n: usize, rng: &mut impl Rng) {
+        let neuron = &self.neurons[source];
+        let pos = (neuron.x, neuron.y, neuron.z);
+        let candidates: Vec<(NeuronId, f64)> = self.neurons.iter().enumerate()
+            .filter(|(id, target)| *id != source && !neuron.ac.contains(&(target.x, target.y, target.z)))
+            .map(|(id, target)| (id, distance(pos, (target.x, target.y, target.z))))
+            .collect();
+
+        // A co-located candidate would have an infinite weight, so distances are floored at the lattice spacing
+        let Ok(chosen) = candidates.sample_weighted(rng, n, |&(_, distance)| 1.0 / distance.max(1.0)) else {
+            return;
+        };
+        let targets: Vec<NeuronId> = chosen.map(|&(id, _)| id).collect();
+        for target in targets {
+            if self.connect(source, target).is_err() {
+                break; // Fan-out limit reached
+            }
+        }
+    }
+
+    // Rewires connections at random, the Watts-Strogatz construction of a small-world network from a locally connected ring or grid.
+    // Each connection existing at the start is considered once, in order of source then target identifier; with the given
+    // probability its target is replaced by a uniformly chosen neuron that is neither the source nor already one of its targets.
+    // The number of connections is preserved. A neuron holding more connections than its `params.max_fan_out` allows
+    // cannot gain a new one, so its connections are left in place.
+    // Parameters:
+    // - `probability`: The probability of rewiring each connection, clamped to [0, 1].
+    // - `rng`: The random number generator drawing the rewiring.
+    // Returns: The number of connections rewired.
+    #[cfg(feature = "rand")]
+    pub fn rewire_random(&mut self, probability: f64, rng: &mut impl Rng) -> usize {
+        let probability = probability.clamp(0.0, 1.0);
+        let edges: Vec<(NeuronId, NeuronId)> = (0..self.neurons.len())
+            .flat_map(|from| self.targets(from).into_iter().map(move |to| (from, to)))
+            .collect();
+
+        let mut rewired = 0;
+        for (from, to) in edges {
+            if !rng.random_bool(probability) {
+                continue;
+            }
+            let current: HashSet<NeuronId> = self.targets(from).into_iter().collect();
+            let candidates: Vec<NeuronId> = (0..self.neurons.len()).filter(|id| *id != from && !current.contains(id)).collect();
+            if let Some(&target) = candidates.choose(rng) {
+                self.disconnect(from, to);
+                if self.connect(from, target).is_err() {
+                    // Restore the original connection, which predates the fan-out limit and would fail its check
+                    let (source, original) = self.pair_mut(from, to);
+                    source.ac.insert((original.x, original.y, original.z));
+                    original.dc.insert((source.x, source.y, source.z));
+                    continue;
+                }
+                rewired += 1;
+            }
+        }
+        rewired
+    }
+
+    // Advances the neurons by one generation of evolutionary optimization over their tunable parameters.
+    // The top `elite_fraction` of neurons by fitness (at least one) are kept unchanged; every other neuron takes the tunable
+    // parameters of a crossover of two random elites, mutated with `EVOLVE_MUTATION_RATE` and `EVOLVE_MUTATION_MAGNITUDE`.
+    // Positions, types, activity state and connections stay with each neuron.
+    // Parameters:
+    // - `fitness`: The score to maximize.
+    // - `elite_fraction`: The fraction of the population kept as elites, clamped to [0, 1].
+    // - `rng`: The random number generator drawing parents and mutations.
+    #[cfg(feature = "rand")]
+    pub fn evolve(&mut self, fitness: impl Fn(&Neuron) -> f64, elite_fraction: f64, rng: &mut impl Rng) {
+        if self.neurons.is_empty() {
+            return;
+        }
+        let mut ranked: Vec<(NeuronId, f64)> = self.neurons.iter().map(&fitness).enumerate().collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+        let elite_count = ((elite_fraction.clamp(0.0, 1.0) * ranked.len() as f64).ceil() as usize).max(1);
+        let elites: Vec<Neuron> = ranked[..elite_count].iter().map(|&(id, _)| self.neurons[id].clone()).collect();
+
+        for &(id, _) in &ranked[elite_count..] {
+            let first = elites.choose(rng).expect("error: the population has at least one elite");
+            let second = elites.choose(rng).expect("error: the population has at least one elite");
+            let mut child = Neuron::crossover(first, second, rng);
+            child.mutate(EVOLVE_MUTATION_RATE, EVOLVE_MUTATION_MAGNITUDE, rng);
+            for (value, inherited) in self.neurons[id].tunable_fields_mut().into_iter().zip(child.tunable_fields()) {
+                *value = inherited;
+            }
+        }
+    }
+
+    // Moves a neuron to an unoccupied coordinate, rewriting its partners' connections and the coordinate index.
+    // Parameters:
+    // - `id`: The identifier of the neuron to move.
+    // - `pos`: The new coordinate.
+    #[cfg(feature = "rand")]
+    fn relocate(&mut self, id: NeuronId, pos: (usize, usize, usize)) {
+        let neuron = &self.neurons[id];
+        let old = (neuron.x, neuron.y, neuron.z);
+        let targets: Vec<_> = neuron.ac.iter().filter_map(|p| self.id_of(*p)).collect();
+        let sources: Vec<_> = neuron.dc.iter().filter_map(|p| self.id_of(*p)).collect();
+
+        for partner in targets {
+            let dc = &mut self.neurons[partner].dc;
+            dc.remove(&old);
+            dc.insert(pos);
+        }
+        for partner in sources {
+            let ac = &mut self.neurons[partner].ac;
+            ac.remove(&old);
+            ac.insert(pos);
+        }
+
+        let neuron = &mut self.neurons[id];
+        (neuron.x, neuron.y, neuron.z) = pos;
+        self.index.remove(&old);
+        self.index.insert(pos, id);
+    }
+
+    // Borrows two distinct neurons mutably at the same time.
+    // Parameters:
+    // - `a`: The identifier of the first neuron.
+    // - `b`: The identifier of the second neuron.
+    // Returns: Mutable references to both neurons, in argument order.
+    fn pair_mut(&mut self, a: NeuronId, b: NeuronId) -> (&mut Neuron, &mut Neuron) {
+        if a == b {
+            panic!("error: {} : a neuron cannot connect to itself", a);
+        }
+        if a < b {
+            let (left, right) = self.neurons.split_at_mut(b);
+            (&mut left[a], &mut right[0])
+        } else {
+            let (left, right) = self.neurons.split_at_mut(a);
+            (&mut right[0], &mut left[b])
+        }
+    }
+}
+
+// Sets a neuron's numeric parameters to the average over a group of neurons.
+// The firing count is summed instead, so the merged neuron keeps the group's total spike history.
+// Parameters:
+// - `neuron`: The neuron receiving the averages.
+// - `group`: The neurons to average.
+fn average_parameters<'a>(neuron: &mut Neuron, group: impl Iterator<Item = &'a Neuron> + Clone) {
+    let count = group.clone().count() as f64;
+    let mean = |field: fn(&Neuron) -> f64| group.clone().map(field).sum::<f64>() / count;
+    neuron.acv = mean(|n| n.acv);
+    neuron.ap = mean(|n| n.ap);
+    neuron.tp = mean(|n| n.tp);
+    neuron.mp = mean(|n| n.mp);
+    neuron.fr = mean(|n| n.fr);
+    neuron.ta = mean(|n| n.ta);
+    neuron.sw = mean(|n| n.sw);
+    neuron.sst = mean(|n| n.sst);
+    neuron.pr = mean(|n| n.pr);
+    neuron.arp = mean(|n| n.arp);
+    neuron.rrp = mean(|n| n.rrp);
+    neuron.nc = mean(|n| n.nc);
+    neuron.receptor_sensitivity = mean(|n| n.receptor_sensitivity);
+    neuron.ltp = mean(|n| n.ltp);
+    neuron.ltd = mean(|n| n.ltd);
+    neuron.fire_count = group.map(|n| n.fire_count).sum();
+}
+
+// Parameters:
+// - `neuron`: The presynaptic neuron.
+// Returns: The neuron's synaptic weight, negated for inhibitory neurons.
+fn signed_weight(neuron: &Neuron) -> f64 {
+    if neuron.nrt == 1 { neuron.sw } else { -neuron.sw }
+}
+
+// Folds a value into an FNV-1a hash, byte by byte in little-endian order so the result is platform independent.
+// Parameters:
+// - `hash`: The hash so far.
+// - `value`: The value to fold in.
+// Returns: The updated hash.
+fn fnv1a(hash: u64, value: u64) -> u64 {
+    value.to_le_bytes().iter().fold(hash, |hash, &byte| (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME))
+}
+
+// Checks whether a coordinate lies within a bounding box.
+// Parameters:
+// - `pos`: The coordinate to check.
+// - `bounds`: The inclusive minimum and maximum corners of the box.
+// Returns: True if every component of `pos` lies within the box.
+pub(crate) fn within_bounds(pos: (usize, usize, usize), bounds: ((usize, usize, usize), (usize, usize, usize))) -> bool {
+    let (min, max) = bounds;
+    (min.0..=max.0).contains(&pos.0) && (min.1..=max.1).contains(&pos.1) && (min.2..=max.2).contains(&pos.2)
+}
+
+// A frontier entry of the strongest path search, ordered by bottleneck strength.
+#[derive(PartialEq)]
+struct PathCandidate {
+    strength: f64,
+    id: NeuronId,
+}
+
+impl Eq for PathCandidate {}
+
+impl Ord for PathCandidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.strength.total_cmp(&other.strength).then_with(|| other.id.cmp(&self.id))
+    }
+}
+
+impl PartialOrd for PathCandidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
@@ -0,0 +1,50 @@
+use serde::{Serialize, Deserialize};
+use crate::{DistanceAttenuation, Neuron};
+
+// Per-neuron tunable parameters that override the corresponding `Neuron` constants.
+// Missing fields deserialize to their defaults, so parameters added later do not break older checkpoints.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct NeuronParams {
+    pub firing_rate_boost_factor: f64,    // firing rate increase per unit of accumulated potential when firing
+    pub firing_rate_decrease_factor: f64, // firing rate decay multiplier when not firing
+    pub threshold_potential_boost_factor_for_firing_rate: f64, // threshold potential increase per unit of firing rate
+    pub threshold_adaptation: bool,       // whether the threshold adapts to activity ( false = fixed threshold )
+    pub cumulative_threshold_adaptation: bool, // whether firing adds a persistent, decaying threshold component ( false = recomputed each update )
+    pub receptor_desensitization_factor: f64, // fraction of receptor sensitivity lost on each fire ( 0.0 = no desensitization )
+    pub receptor_recovery_factor: f64,    // fraction of lost receptor sensitivity recovered per idle tick
+    pub leak_rate: f64,                   // fraction of accumulated potential lost per unit of time ( 0.0 = no leak )
+    pub tau: f64,                         // membrane time constant of the RC-style potential update ( 0.0 = instantaneous )
+    pub min_synaptic_delay: f64,          // baseline delay in milliseconds added to every incoming signal delay
+    pub max_fan_out: Option<usize>,       // maximum number of axonal connections ( None = unlimited )
+    pub distance_attenuation: DistanceAttenuation, // how incoming signals are scaled by the distance to their source
+    pub conductance_based: bool,          // whether inputs are conductances scaled by the driving force ( false = currents )
+    pub reversal_exc: f64,                // reversal potential of excitatory conductances
+    pub reversal_inh: f64,                // reversal potential of inhibitory conductances
+    pub noise_std: f64,                   // standard deviation of the membrane noise added per signal ( 0.0 = none , requires `rand` )
+    pub stochastic_gain: f64,             // steepness of the logistic firing probability used by stochastic detection
+}
+
+impl Default for NeuronParams {
+    fn default() -> Self {
+        NeuronParams {
+            firing_rate_boost_factor: Neuron::FIRING_RATE_BOOST_FACTOR,
+            firing_rate_decrease_factor: Neuron::FIRING_RATE_DECREASE_FACTOR,
+            threshold_potential_boost_factor_for_firing_rate: Neuron::THRESHOLD_POTENTIAL_BOOST_FACTOR_FOR_FIRING_RATE,
+            threshold_adaptation: true,
+            cumulative_threshold_adaptation: false,
+            receptor_desensitization_factor: Neuron::RECEPTOR_DESENSITIZATION_FACTOR,
+            receptor_recovery_factor: Neuron::RECEPTOR_RECOVERY_FACTOR,
+            leak_rate: 0.0,
+            tau: Neuron::MEMBRANE_TIME_CONSTANT,
+            min_synaptic_delay: Neuron::MIN_SYNAPTIC_DELAY,
+            max_fan_out: None,
+            distance_attenuation: DistanceAttenuation::None,
+            conductance_based: false,
+            reversal_exc: Neuron::EXCITATORY_REVERSAL_POTENTIAL,
+            reversal_inh: Neuron::INHIBITORY_REVERSAL_POTENTIAL,
+            noise_std: 0.0,
+            stochastic_gain: Neuron::STOCHASTIC_GAIN,
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use crate::coord::into_tuple;
+use crate::network::within_bounds;
+use crate::{Coord3, Network, Neuron, PersistenceError};
+
+// Version of the layout written by `Network::save_versioned`; files of a newer version are rejected on load.
+const FORMAT_VERSION: u32 = 1;
+
+// A connection that crosses the boundary of a saved region.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExternalPort {
+    pub neuron: (usize, usize, usize), // coordinate of the neuron inside the region
+    pub remote: (usize, usize, usize), // coordinate of the partner outside the region
+    pub outgoing: bool,                // true = axonal connection, false = dendritic connection
+}
+
+// The layout of saved JSON files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsonFormat {
+    Pretty,  // indented and human readable
+    Compact, // no whitespace, for large networks
+}
+
+// The persisted form of a spatial region of a network.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct Region {
+    bounds: ((usize, usize, usize), (usize, usize, usize)),
+    neurons: Vec<Neuron>,
+    external_ports: Vec<ExternalPort>,
+}
+
+// The persisted form of a whole network. The checksum covers the compact serialization of `neurons`, so it does not
+// depend on whether the file was written pretty-printed or compact.
+#[derive(Serialize, Deserialize, Debug)]
+struct VersionedFile {
+    version: u32,
+    checksum: u32,
+    neurons: Value,
+}
+
+impl Network {
+    // Saves the neurons within a bounding box as JSON.
+    // Connections to coordinates outside the box are clipped from the saved neurons and recorded as external ports.
+    // Parameters:
+    // - `bounds`: The inclusive minimum and maximum corners of the box.
+    // - `path`: The file to write.
+    // - `format`: Whether to pretty-print the JSON or write it compactly.
+    // Returns: `PersistenceError::Io` if the file cannot be written.
+    pub fn save_region<P: AsRef<Path>>(&self, bounds: (impl Into<Coord3>, impl Into<Coord3>), path: P, format: JsonFormat) -> Result<(), PersistenceError> {
+        let bounds = (into_tuple(bounds.0), into_tuple(bounds.1));
+        let mut region = Region { bounds, neurons: Vec::new(), external_ports: Vec::new() };
+
+        for neuron in self.neurons().iter().filter(|neuron| within_bounds((neuron.x, neuron.y, neuron.z), bounds)) {
+            let mut neuron = neuron.clone();
+            let pos = (neuron.x, neuron.y, neuron.z);
+            for (connections, outgoing) in [(&mut neuron.ac, true), (&mut neuron.dc, false)] {
+                connections.retain(|&remote| {
+                    let inside = within_bounds(remote, bounds);
+                    if !inside {
+                        region.external_ports.push(ExternalPort { neuron: pos, remote, outgoing });
+                    }
+                    inside
+                });
+            }
+            region.neurons.push(neuron);
+        }
+
+        let writer = BufWriter::new(File::create(path)?);
+        match format {
+            JsonFormat::Pretty => serde_json::to_writer_pretty(writer, &region)?,
+            JsonFormat::Compact => serde_json::to_writer(writer, &region)?,
+        }
+        Ok(())
+    }
+
+    // Loads a region saved by `save_region`.
+    // Parameters:
+    // - `path`: The file to read.
+    // Returns: A network of the saved neurons together with the external ports of the region,
+    // `PersistenceError::Io` if the file cannot be read, or `PersistenceError::Serde` if it is malformed.
+    pub fn load_region<P: AsRef<Path>>(path: P) -> Result<(Network, Vec<ExternalPort>), PersistenceError> {
+        let reader = BufReader::new(File::open(path)?);
+        let region: Region = serde_json::from_reader(reader)?;
+
+        let mut network = Network::new();
+        for neuron in region.neurons {
+            network.add_neuron(neuron);
+        }
+        Ok((network, region.external_ports))
+    }
+
+    // Saves every neuron as JSON tagged with the format version and a CRC-32 checksum of the neuron data,
+    // so corruption of a long-lived checkpoint is detected by `load_versioned`.
+    // The checksum is taken over the compact form of the neuron data, so pretty-printed files verify as well.
+    // Parameters:
+    // - `path`: The file to write.
+    // - `format`: Whether to pretty-print the JSON or write it compactly.
+    // Returns: `PersistenceError::Io` if the file cannot be written.
+    pub fn save_versioned<P: AsRef<Path>>(&self, path: P, format: JsonFormat) -> Result<(), PersistenceError> {
+        let neurons = serde_json::to_value(self.neurons())?;
+        let file = VersionedFile {
+            version: FORMAT_VERSION,
+            checksum: checksum(&neurons)?,
+            neurons,
+        };
+        let writer = BufWriter::new(File::create(path)?);
+        match format {
+            JsonFormat::Pretty => serde_json::to_writer_pretty(writer, &file)?,
+            JsonFormat::Compact => serde_json::to_writer(writer, &file)?,
+        }
+        Ok(())
+    }
+
+    // Loads a network saved by `save_versioned`, verifying its format version and checksum.
+    // Parameters:
+    // - `path`: The file to read.
+    // Returns: The network, `PersistenceError::UnsupportedVersion` for a file of a newer format,
+    // or `PersistenceError::ChecksumMismatch` if the neuron data was altered.
+    pub fn load_versioned<P: AsRef<Path>>(path: P) -> Result<Network, PersistenceError> {
+        let reader = BufReader::new(File::open(path)?);
+        let file: VersionedFile = serde_json::from_reader(reader)?;
+        if file.version > FORMAT_VERSION {
+            return Err(PersistenceError::UnsupportedVersion(file.version));
+        }
+        if checksum(&file.neurons)? != file.checksum {
+            return Err(PersistenceError::ChecksumMismatch);
+        }
+
+        let mut network = Network::new();
+        for neuron in serde_json::from_value::<Vec<Neuron>>(file.neurons)? {
+            network.add_neuron(neuron);
+        }
+        Ok(network)
+    }
+}
+
+// Computes the checksum of versioned neuron data over its compact serialization, independent of the file's whitespace.
+// Relies on serde_json's `float_roundtrip` feature, so reparsed floats serialize to the same digits as when saved.
+// Parameters:
+// - `neurons`: The parsed neuron data.
+// Returns: The CRC-32 checksum of the compact JSON.
+fn checksum(neurons: &Value) -> Result<u32, PersistenceError> {
+    Ok(crc32(serde_json::to_string(neurons)?.as_bytes()))
+}
+
+// Computes the CRC-32 (IEEE 802.3) checksum of a byte sequence.
+// Parameters:
+// - `bytes`: The data to checksum.
+// Returns: The checksum.
+fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0u32, |crc, &byte| {
+        (0..8).fold(crc ^ u32::from(byte), |crc, _| if crc & 1 == 1 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 })
+    })
+}
@@ -0,0 +1,33 @@
+use core::fmt::Debug;
+use serde::{Serialize, Deserialize};
+use crate::Neuron;
+
+// A learning rule that updates a neuron's synaptic weight after each transmitted signal.
+pub trait PlasticityRule: Debug + Send + Sync {
+    // Applies the rule to a neuron.
+    // Parameters:
+    // - `neuron`: A mutable reference to the neuron whose weight is updated.
+    // - `input`: The input signal value that triggered the update.
+    fn apply(&self, neuron: &mut Neuron, input: f64);
+}
+
+// The default rule: folds the combined LTP and LTD, scaled by the plasticity rate, into the synaptic weight.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AdditiveRule;
+
+impl PlasticityRule for AdditiveRule {
+    fn apply(&self, neuron: &mut Neuron, _input: f64) {
+        neuron.update_sw();
+    }
+}
+
+// A snapshot of a neuron's learning state.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct PlasticitySummary {
+    pub ltp: f64,              // long term potentiation factor
+    pub ltd: f64,              // long term depression factor
+    pub pr: f64,               // plasticity rate
+    pub sst: f64,              // synaptic strength threshold
+    pub sw: f64,               // synaptic weight
+    pub net_potentiation: f64, // ltp + ltd
+}
@@ -0,0 +1,124 @@
+use std::collections::HashSet;
+use serde::{Serialize, Deserialize};
+use crate::NeuronId;
+
+// A record of every spike emitted during a network run, in firing order.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct SpikeRaster {
+    spikes: Vec<(NeuronId, u64)>, // ( neuron identifier , tick ) per spike
+}
+
+impl SpikeRaster {
+    // Creates an empty spike raster.
+    pub fn new() -> Self {
+        SpikeRaster { spikes: Vec::new() }
+    }
+
+    // Records a spike.
+    // Parameters:
+    // - `id`: The identifier of the neuron that fired.
+    // - `tick`: The tick at which the neuron fired.
+    pub fn record(&mut self, id: NeuronId, tick: u64) {
+        self.spikes.push((id, tick));
+    }
+
+    // Returns: The recorded `(neuron id, tick)` spikes in tick order.
+    pub fn spikes(&self) -> &[(NeuronId, u64)] {
+        &self.spikes
+    }
+
+    // Parameters:
+    // - `id`: The identifier of the neuron.
+    // Returns: The ticks at which the neuron fired, in ascending order.
+    pub fn spike_train(&self, id: NeuronId) -> Vec<u64> {
+        self.spikes.iter().filter(|(spike, _)| *spike == id).map(|&(_, tick)| tick).collect()
+    }
+
+    // Returns: The number of recorded spikes.
+    pub fn len(&self) -> usize {
+        self.spikes.len()
+    }
+
+    // Returns: True if no spikes were recorded.
+    pub fn is_empty(&self) -> bool {
+        self.spikes.is_empty()
+    }
+
+    // Moves each recorded spike to the neuron's new identifier after neurons were removed from the network.
+    // Parameters:
+    // - `renumber`: The new identifier of each old identifier.
+    pub(crate) fn renumber(&mut self, renumber: &[NeuronId]) {
+        for (id, _) in &mut self.spikes {
+            *id = renumber[*id];
+        }
+    }
+
+    // Removes every recorded spike.
+    pub fn clear(&mut self) {
+        self.spikes.clear();
+    }
+
+    // Exports the raster as CSV with a header row.
+    // Returns: One `neuron_id,tick` row per spike, in tick order.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("neuron_id,tick\n");
+        for (id, tick) in &self.spikes {
+            csv.push_str(&format!("{},{}\n", id, tick));
+        }
+        csv
+    }
+}
+
+// Inter-spike interval statistics of a spike train, characterizing firing regularity.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq)]
+pub struct IsiStats {
+    pub count: usize, // number of intervals
+    pub mean: f64,    // mean interval in ticks
+    pub cv: f64,      // coefficient of variation ( near 0 = regular , near 1 = Poisson-like )
+    pub min: u64,     // shortest interval in ticks
+    pub max: u64,     // longest interval in ticks
+}
+
+// Computes the cross-correlation of two spike trains over a range of lags.
+// The correlation at lag `l` counts the spikes of `a` at tick `t` matched by a spike of `b` at tick `t + l`,
+// normalized by the geometric mean of the train lengths. A peak at a positive lag suggests `a` drives `b`.
+// Parameters:
+// - `a`: The spike ticks of the first neuron.
+// - `b`: The spike ticks of the second neuron.
+// - `max_lag`: The largest lag, in ticks, in either direction.
+// Returns: One `(lag, correlation)` pair per lag from `-max_lag` to `max_lag`; correlations are 0.0 if either train is empty.
+pub fn spike_cross_correlation(a: &[u64], b: &[u64], max_lag: i64) -> Vec<(i64, f64)> {
+    let b_ticks: HashSet<i64> = b.iter().map(|&tick| tick as i64).collect();
+    let norm = ((a.len() * b.len()) as f64).sqrt();
+    (-max_lag..=max_lag).map(|lag| {
+        if norm == 0.0 {
+            return (lag, 0.0);
+        }
+        let matches = a.iter().filter(|&&tick| b_ticks.contains(&(tick as i64 + lag))).count();
+        (lag, matches as f64 / norm)
+    }).collect()
+}
+
+// Computes the inter-spike interval statistics of a spike train.
+// Parameters:
+// - `spike_ticks`: The spike ticks of one neuron, e.g. from `SpikeRaster::spike_train`, in any order.
+// Returns: The statistics of the intervals between consecutive spikes; all zero with fewer than two spikes.
+pub fn isi_statistics(spike_ticks: &[u64]) -> IsiStats {
+    let mut ticks = spike_ticks.to_vec();
+    ticks.sort_unstable();
+    let intervals: Vec<u64> = ticks.windows(2).map(|pair| pair[1] - pair[0]).collect();
+    if intervals.is_empty() {
+        return IsiStats::default();
+    }
+
+    let count = intervals.len();
+    let mean = intervals.iter().sum::<u64>() as f64 / count as f64;
+    let variance = intervals.iter().map(|&interval| (interval as f64 - mean).powi(2)).sum::<f64>() / count as f64;
+    IsiStats {
+        count,
+        mean,
+        cv: if mean > 0.0 { variance.sqrt() / mean } else { 0.0 },
+        min: intervals.iter().copied().min().unwrap_or(0),
+        max: intervals.iter().copied().max().unwrap_or(0),
+    }
+}
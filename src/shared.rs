@@ -0,0 +1,62 @@
+use std::sync::{Arc, PoisonError, RwLock};
+use crate::{Network, Neuron, NeuronId};
+
+// Compile-time guarantees that neurons and networks can be moved and shared across threads.
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Neuron>();
+    assert_send_sync::<Network>();
+};
+
+// A thread-safe handle to a network, letting analysis threads read it while a stepping thread advances it.
+// Clones share the same network. Any number of readers may hold it at once; a writer holds it exclusively.
+// A lock poisoned by a panicking thread is recovered, since the network remains structurally valid between method calls.
+#[derive(Debug, Clone, Default)]
+pub struct SharedNetwork {
+    inner: Arc<RwLock<Network>>, // the shared network
+}
+
+impl SharedNetwork {
+    // Wraps a network for sharing across threads.
+    // Parameters:
+    // - `network`: The network to share.
+    pub fn new(network: Network) -> Self {
+        SharedNetwork { inner: Arc::new(RwLock::new(network)) }
+    }
+
+    // Runs a closure with shared read access, concurrently with other readers.
+    // Parameters:
+    // - `f`: The closure receiving the network.
+    // Returns: The closure's result.
+    pub fn read<R>(&self, f: impl FnOnce(&Network) -> R) -> R {
+        f(&self.inner.read().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    // Runs a closure with exclusive write access, blocking readers and other writers until it returns.
+    // Parameters:
+    // - `f`: The closure receiving the network.
+    // Returns: The closure's result.
+    pub fn write<R>(&self, f: impl FnOnce(&mut Network) -> R) -> R {
+        f(&mut self.inner.write().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    // Advances the shared network by one tick under the write lock.
+    // Returns: The neurons that fired during the tick, paired with their output signal.
+    pub fn tick(&self) -> Vec<(NeuronId, f64)> {
+        self.write(Network::tick)
+    }
+
+    // Returns: A copy of the network at this moment, for analysis without holding the lock.
+    pub fn snapshot(&self) -> Network {
+        self.read(Network::clone)
+    }
+
+    // Unwraps the network once no other handle shares it.
+    // Returns: The network, or this handle back if other clones are still alive.
+    pub fn try_into_inner(self) -> Result<Network, Self> {
+        match Arc::try_unwrap(self.inner) {
+            Ok(lock) => Ok(lock.into_inner().unwrap_or_else(PoisonError::into_inner)),
+            Err(inner) => Err(SharedNetwork { inner }),
+        }
+    }
+}
@@ -0,0 +1,411 @@
+// tests/test_core.rs
+// Exercises only the core neuron model, so it also builds and runs with `--no-default-features` (no `std`).
+use neuron::{ConnectionDirection, ConnectionSet, Coord3, InhibitionMode, Neuron, NeuronBuilder, NeuronError};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_core_signal_path() {
+        let mut neuron = Neuron::new(0, 0, 0, 1, 1, 1, 1, 1);
+        let mut target = Neuron::new(1, 1, 1, 2, 2, 2, 2, 1);
+        neuron.establish_axonal_connection(&mut target).unwrap();
+        assert_eq!(neuron.ac, ConnectionSet::from([(1, 1, 1)]), "({}, {}, {}) -> Expected the axonal connection to be recorded", neuron.x, neuron.y, neuron.z);
+
+        neuron.transmit_sync(20.0);
+        let output = neuron.detect();
+        assert!(output > 0.0, "({}, {}, {}) -> Expected the core signal path to fire, got {}", neuron.x, neuron.y, neuron.z, output);
+        target.transmit_sync(output);
+        assert!(target.ap > 0.0, "({}, {}, {}) -> Expected the signal to accumulate potential", target.x, target.y, target.z);
+    }
+
+    #[test]
+    fn test_clamp_to_params() {
+        let mut neuron = Neuron::new(1, 1, 1, 2, 2, 2, 1, 1);
+        assert_eq!(neuron.validate(), Ok(()), "({}, {}, {}) -> Expected a new neuron to be valid", neuron.x, neuron.y, neuron.z);
+
+        neuron.mp = 0.0;
+        neuron.tp = -80.0;
+        neuron.fr = 5.0;
+        neuron.sw = -3.0;
+        neuron.ltp = 2.0;
+        neuron.ltd = 0.5;
+        assert_eq!(neuron.validate(), Err(NeuronError::OutOfRange { field: "mp", value: 0.0 }), "({}, {}, {}) -> Expected the first out-of-range field to be reported", neuron.x, neuron.y, neuron.z);
+
+        neuron.clamp_to_params();
+        assert_eq!(neuron.validate(), Ok(()), "({}, {}, {}) -> Expected the clamped neuron to be valid", neuron.x, neuron.y, neuron.z);
+        assert_eq!((neuron.mp, neuron.tp, neuron.fr), (Neuron::MAX_MEMBRANE_POTENTIAL, Neuron::MIN_THRESHOLD_POTENTIAL, Neuron::MAX_FIRING_RATE), "({}, {}, {}) -> Expected potentials and firing rate at their bounds", neuron.x, neuron.y, neuron.z);
+        assert_eq!((neuron.sw, neuron.ltp, neuron.ltd), (Neuron::MIN_LTD, Neuron::MAX_LTP, 0.0), "({}, {}, {}) -> Expected plasticity fields at their bounds", neuron.x, neuron.y, neuron.z);
+    }
+
+    #[test]
+    fn test_connection_direction() {
+        let mut neuron = Neuron::new(1, 1, 1, 2, 2, 2, 0, 1);
+        let mut upstream = Neuron::new(0, 0, 0, 1, 1, 0, 0, 1);
+        let mut downstream = Neuron::new(3, 3, 3, 4, 4, 4, 0, 1);
+        let mut partner = Neuron::new(5, 5, 5, 6, 6, 6, 0, 1);
+        upstream.establish_axonal_connection(&mut neuron).unwrap();
+        neuron.establish_axonal_connection(&mut downstream).unwrap();
+        neuron.establish_axonal_connection(&mut partner).unwrap();
+        partner.establish_axonal_connection(&mut neuron).unwrap();
+
+        for (pos, expected) in [
+            ((9, 9, 9), ConnectionDirection::Unconnected),
+            ((0, 0, 0), ConnectionDirection::Afferent),
+            ((3, 3, 3), ConnectionDirection::Efferent),
+            ((5, 5, 5), ConnectionDirection::Reciprocal),
+        ] {
+            assert_eq!(neuron.connection_direction(pos), expected, "({}, {}, {}) -> Expected the connection with {:?} to be {:?}", neuron.x, neuron.y, neuron.z, pos, expected);
+        }
+    }
+
+    #[test]
+    fn test_axon_at_soma_strictness() {
+        let strict = NeuronBuilder::new(1, 2, 3).strict(true).build();
+        assert_eq!(strict.unwrap_err(), NeuronError::AxonAtSoma((1, 2, 3)), "Expected strict mode to reject a coincident axon");
+        assert!(Neuron::try_new(1, 2, 3, 1, 2, 3, 0, 1, true).is_err(), "Expected strict try_new to reject a coincident axon");
+
+        let lenient = NeuronBuilder::new(1, 2, 3).neurotransmitter(0).build().unwrap();
+        assert_eq!((lenient.ax, lenient.ay, lenient.az, lenient.nrt), (1, 2, 3, 0), "({}, {}, {}) -> Expected lenient mode to allow a coincident axon", lenient.x, lenient.y, lenient.z);
+        let neuron = Neuron::try_new(1, 2, 3, 2, 3, 4, 2, 1, true).unwrap();
+        assert_eq!((neuron.ax, neuron.ay, neuron.az, neuron.nt), (2, 3, 4, 2), "({}, {}, {}) -> Expected strict mode to accept a distinct axon", neuron.x, neuron.y, neuron.z);
+    }
+
+    #[test]
+    fn test_invalid_types_rejected() {
+        assert_eq!(Neuron::try_new(1, 2, 3, 2, 3, 4, 7, 1, false).unwrap_err(), NeuronError::InvalidNeuronType(7), "Expected try_new to reject neuron type 7");
+        assert_eq!(Neuron::try_new(1, 2, 3, 2, 3, 4, 0, 2, true).unwrap_err(), NeuronError::InvalidNeurotransmitterType(2), "Expected try_new to reject neurotransmitter type 2");
+        let built = NeuronBuilder::new(1, 2, 3).axon(2, 3, 4).neuron_type(3).build();
+        assert_eq!(built.unwrap_err(), NeuronError::InvalidNeuronType(3), "Expected the builder to reject neuron type 3");
+        assert_eq!(NeuronError::InvalidNeuronType(7).to_string(), "7 : nt must be 0, 1, or 2", "Expected the type in the error message");
+    }
+
+    #[test]
+    fn test_to_builder_round_trip() {
+        let mut neuron = NeuronBuilder::new(1, 2, 3).axon(2, 3, 4).neuron_type(2).neurotransmitter(0).build().unwrap();
+        neuron.params.leak_rate = 0.25;
+
+        let rebuilt = neuron.to_builder().build().unwrap();
+        assert_eq!(rebuilt.to_builder(), neuron.to_builder(), "({}, {}, {}) -> Expected an unchanged builder to reproduce the neuron", neuron.x, neuron.y, neuron.z);
+        assert_eq!(((rebuilt.x, rebuilt.y, rebuilt.z), (rebuilt.ax, rebuilt.ay, rebuilt.az), rebuilt.nt, rebuilt.nrt), ((1, 2, 3), (2, 3, 4), 2, 0), "({}, {}, {}) -> Expected the construction parameters to round-trip", rebuilt.x, rebuilt.y, rebuilt.z);
+        assert_eq!(rebuilt.params, neuron.params, "({}, {}, {}) -> Expected the tunable parameters to round-trip", rebuilt.x, rebuilt.y, rebuilt.z);
+        assert_eq!((rebuilt.mp, rebuilt.sw, rebuilt.nc), (neuron.mp, neuron.sw, neuron.nc), "({}, {}, {}) -> Expected an equivalent initial state", rebuilt.x, rebuilt.y, rebuilt.z);
+
+        let modified = neuron.to_builder().neurotransmitter(1).build().unwrap();
+        assert_eq!(modified.nrt, 1, "({}, {}, {}) -> Expected the changed field to be applied", modified.x, modified.y, modified.z);
+        assert_eq!(modified.to_builder().neurotransmitter(0), neuron.to_builder(), "({}, {}, {}) -> Expected every other field to be unchanged", modified.x, modified.y, modified.z);
+    }
+
+    #[test]
+    fn test_fire_transform() {
+        let mut neuron = Neuron::new(1, 1, 1, 2, 2, 2, 1, 1);
+        neuron.set_fire_transform(Box::new(|_| 7.5));
+        neuron.transmit_sync(20.0);
+        assert_eq!(neuron.detect(), 7.5, "({}, {}, {}) -> Expected the custom transform to set the output", neuron.x, neuron.y, neuron.z);
+        assert_eq!(neuron.ap, 0.0, "({}, {}, {}) -> Expected fire to still reset the accumulated potential", neuron.x, neuron.y, neuron.z);
+
+        let mut copy = neuron.clone();
+        copy.clear_fire_transform();
+        for n in [&mut neuron, &mut copy] {
+            (n.arp, n.rrp) = (0.0, Neuron::BASE_RELATIVE_REFRACTORY_PERIOD);
+            n.transmit_sync(20.0);
+        }
+        assert_eq!(neuron.detect(), 7.5, "({}, {}, {}) -> Expected the transform to survive cloning the neuron", neuron.x, neuron.y, neuron.z);
+        assert_ne!(copy.detect(), 7.5, "({}, {}, {}) -> Expected the built-in formula after clearing the transform", copy.x, copy.y, copy.z);
+    }
+
+    #[test]
+    fn test_integrate_sub_steps() {
+        let mut coarse = Neuron::new(1, 1, 1, 2, 2, 2, 1, 1);
+        coarse.params.leak_rate = 0.1;
+        let mut fine = coarse.clone();
+
+        let start = coarse.mp;
+        coarse.integrate(15.0, 1.0, 1);
+        let coarse_jump = (coarse.mp - start).abs();
+
+        let mut trajectory = vec![fine.mp];
+        for _ in 0..10 {
+            fine.integrate(15.0, 0.1, 1);
+            trajectory.push(fine.mp);
+        }
+        let fine_jump = trajectory.windows(2).map(|w| (w[1] - w[0]).abs()).fold(0.0, f64::max);
+
+        assert!(fine_jump < coarse_jump / 5.0, "({}, {}, {}) -> Expected sub-steps to move the potential smoothly, got a step of {} against {}", fine.x, fine.y, fine.z, fine_jump, coarse_jump);
+        assert!((fine.mp - coarse.mp).abs() < coarse_jump * 0.1, "({}, {}, {}) -> Expected comparable endpoints, got {} and {}", fine.x, fine.y, fine.z, fine.mp, coarse.mp);
+
+        let mut split = Neuron::new(1, 1, 1, 2, 2, 2, 1, 1);
+        split.integrate(15.0, 0.25, 4);
+        let mut whole = Neuron::new(1, 1, 1, 2, 2, 2, 1, 1);
+        whole.transmit_sync(15.0);
+        assert!((split.mp - whole.mp).abs() < 1e-9, "({}, {}, {}) -> Expected unit total time without leak to match transmit_sync, got {} and {}", split.x, split.y, split.z, split.mp, whole.mp);
+    }
+
+    #[test]
+    fn test_membrane_time_constant() {
+        let mut neuron = Neuron::new(1, 1, 1, 2, 2, 2, 1, 1);
+        neuron.params.tau = 5.0;
+        neuron.integrate(15.0, 1.0, 1);
+        let target = Neuron::RESTING_POTENTIAL + neuron.ap;
+        assert!((neuron.mp - target).abs() > 1.0, "({}, {}, {}) -> Expected the potential not to reach its target {} immediately, got {}", neuron.x, neuron.y, neuron.z, target, neuron.mp);
+
+        let mut gap = (target - neuron.mp).abs();
+        for _ in 0..30 {
+            neuron.integrate(0.0, 1.0, 1);
+            let next = (target - neuron.mp).abs();
+            assert!(next < gap, "({}, {}, {}) -> Expected the potential to keep approaching its target, gap {} -> {}", neuron.x, neuron.y, neuron.z, gap, next);
+            gap = next;
+        }
+        assert!(gap < 0.1, "({}, {}, {}) -> Expected the potential to settle near its target, gap {}", neuron.x, neuron.y, neuron.z, gap);
+
+        let mut fast = Neuron::new(1, 1, 1, 2, 2, 2, 1, 1);
+        fast.params.tau = 1e-6;
+        fast.integrate(15.0, 1.0, 1);
+        assert_eq!(fast.mp, Neuron::RESTING_POTENTIAL + fast.ap, "({}, {}, {}) -> Expected a tiny time constant to update instantly", fast.x, fast.y, fast.z);
+    }
+
+    #[test]
+    fn test_feature_vector() {
+        let mut neuron = Neuron::new(1, 1, 1, 2, 2, 2, 2, 0);
+        neuron.transmit_sync(20.0);
+        neuron.detect();
+        let features = neuron.as_feature_vector();
+        let names = Neuron::feature_names();
+        assert_eq!(features.len(), names.len(), "({}, {}, {}) -> Expected one name per feature", neuron.x, neuron.y, neuron.z);
+        assert_eq!(features.len(), Neuron::FEATURE_COUNT, "({}, {}, {}) -> Expected {} features", neuron.x, neuron.y, neuron.z, Neuron::FEATURE_COUNT);
+
+        let feature = |name: &str| features[names.iter().position(|n| *n == name).unwrap()];
+        assert_eq!(&names[..3], &["mp", "tp", "fr"], "({}, {}, {}) -> Expected the potentials first", neuron.x, neuron.y, neuron.z);
+        assert_eq!(feature("fr"), neuron.fr / Neuron::MAX_FIRING_RATE, "({}, {}, {}) -> Expected the firing rate at its named position", neuron.x, neuron.y, neuron.z);
+        assert_eq!(feature("nc"), 0.5, "({}, {}, {}) -> Expected the default concentration at half range", neuron.x, neuron.y, neuron.z);
+        assert_eq!((feature("nt_motor"), feature("nt_contact"), feature("nrt_inhibitory")), (1.0, 0.0, 1.0), "({}, {}, {}) -> Expected one-hot types", neuron.x, neuron.y, neuron.z);
+        for (name, value) in names.iter().zip(features) {
+            assert!((0.0..=1.0).contains(&value), "({}, {}, {}) -> Expected feature {} within [0, 1], got {}", neuron.x, neuron.y, neuron.z, name, value);
+        }
+    }
+
+    #[test]
+    fn test_blend() {
+        let mut a = Neuron::new(1, 1, 1, 2, 2, 2, 1, 1);
+        let mut b = Neuron::new(5, 5, 5, 6, 6, 6, 2, 1);
+        a.establish_axonal_connection(&mut b).unwrap();
+        b.transmit_sync(25.0);
+        b.detect();
+        b.sw = 0.4;
+        b.nc = 1.5;
+
+        let blended = Neuron::blend(&a, &b, 0.5);
+        let fields = [
+            ("acv", a.acv, b.acv, blended.acv), ("ap", a.ap, b.ap, blended.ap), ("tp", a.tp, b.tp, blended.tp), ("mp", a.mp, b.mp, blended.mp),
+            ("fr", a.fr, b.fr, blended.fr), ("ta", a.ta, b.ta, blended.ta), ("sw", a.sw, b.sw, blended.sw), ("sst", a.sst, b.sst, blended.sst),
+            ("pr", a.pr, b.pr, blended.pr), ("arp", a.arp, b.arp, blended.arp), ("rrp", a.rrp, b.rrp, blended.rrp), ("nc", a.nc, b.nc, blended.nc),
+            ("receptor_sensitivity", a.receptor_sensitivity, b.receptor_sensitivity, blended.receptor_sensitivity), ("ltp", a.ltp, b.ltp, blended.ltp), ("ltd", a.ltd, b.ltd, blended.ltd),
+        ];
+        for (name, x, y, value) in fields {
+            assert!((value - (x + y) / 2.0).abs() < 1e-12, "({}, {}, {}) -> Expected {} to be the midpoint of {} and {}, got {}", blended.x, blended.y, blended.z, name, x, y, value);
+        }
+        assert!(fields.iter().any(|(_, x, y, _)| x != y), "({}, {}, {}) -> Expected the parents to differ", blended.x, blended.y, blended.z);
+        assert_eq!(((blended.x, blended.y, blended.z), blended.nt, &blended.ac), ((a.x, a.y, a.z), a.nt, &a.ac), "({}, {}, {}) -> Expected position, type and connections from the first neuron", blended.x, blended.y, blended.z);
+        assert_eq!(Neuron::blend(&a, &b, 7.0).sw, b.sw, "({}, {}, {}) -> Expected t to clamp to 1", blended.x, blended.y, blended.z);
+    }
+
+    #[test]
+    fn test_transmit_many_inhibition_modes() {
+        let inputs = [12.0, 8.0, -5.0];
+        let mut subtractive = Neuron::new(1, 1, 1, 2, 2, 2, 1, 1);
+        let mut shunting = subtractive.clone();
+        let mut excitation_only = subtractive.clone();
+        subtractive.transmit_many(&inputs, InhibitionMode::Subtractive);
+        shunting.transmit_many(&inputs, InhibitionMode::Shunting);
+        excitation_only.transmit_many(&inputs[..2], InhibitionMode::Subtractive);
+
+        let mut summed = Neuron::new(1, 1, 1, 2, 2, 2, 1, 1);
+        summed.transmit_sync(15.0);
+        assert_eq!(subtractive.ap, summed.ap, "({}, {}, {}) -> Expected subtractive inhibition to add inputs algebraically", subtractive.x, subtractive.y, subtractive.z);
+        assert_ne!(shunting.ap, subtractive.ap, "({}, {}, {}) -> Expected the modes to differ", shunting.x, shunting.y, shunting.z);
+        let factor = 1.0 / (1.0 + 5.0 / Neuron::SHUNTING_INHIBITION_CONSTANT);
+        assert!((shunting.ap - excitation_only.ap * factor).abs() < 1e-9, "({}, {}, {}) -> Expected shunting to scale the excitatory drive by {}, got {} from {}", shunting.x, shunting.y, shunting.z, factor, shunting.ap, excitation_only.ap);
+
+        let mut inhibited = Neuron::new(1, 1, 1, 2, 2, 2, 1, 1);
+        inhibited.transmit_many(&[-10.0], InhibitionMode::Shunting);
+        assert_eq!(inhibited.ap, 0.0, "({}, {}, {}) -> Expected shunting inhibition alone not to hyperpolarize", inhibited.x, inhibited.y, inhibited.z);
+    }
+
+    #[test]
+    fn test_max_fan_out() {
+        let mut neuron = Neuron::new(0, 0, 0, 1, 0, 0, 1, 1);
+        neuron.params.max_fan_out = Some(2);
+        let mut targets = [Neuron::new(1, 1, 1, 2, 2, 2, 0, 1), Neuron::new(2, 2, 2, 3, 3, 3, 0, 1), Neuron::new(3, 3, 3, 4, 4, 4, 0, 1)];
+
+        let [first, second, third] = &mut targets;
+        assert_eq!(neuron.establish_axonal_connection(first), Ok(()), "({}, {}, {}) -> Expected the first connection to succeed", neuron.x, neuron.y, neuron.z);
+        assert_eq!(neuron.establish_axonal_connection(second), Ok(()), "({}, {}, {}) -> Expected the second connection to succeed", neuron.x, neuron.y, neuron.z);
+        assert_eq!(neuron.establish_axonal_connection(third), Err(NeuronError::FanOutExceeded { pos: (0, 0, 0), limit: 2 }), "({}, {}, {}) -> Expected the third connection to be rejected", neuron.x, neuron.y, neuron.z);
+        assert_eq!(third.establish_dendritic_connection(&mut neuron), Err(NeuronError::FanOutExceeded { pos: (0, 0, 0), limit: 2 }), "({}, {}, {}) -> Expected the limit to apply from the dendritic side too", neuron.x, neuron.y, neuron.z);
+
+        assert_eq!(neuron.ac, ConnectionSet::from([(1, 1, 1), (2, 2, 2)]), "({}, {}, {}) -> Expected only the first two connections", neuron.x, neuron.y, neuron.z);
+        assert!(third.dc.is_empty(), "({}, {}, {}) -> Expected the rejected target to gain no back-reference", third.x, third.y, third.z);
+        assert_eq!(neuron.establish_axonal_connection(first), Ok(()), "({}, {}, {}) -> Expected re-establishing an existing connection to succeed at the limit", neuron.x, neuron.y, neuron.z);
+    }
+
+    #[test]
+    fn test_advance_refractory() {
+        let mut neuron = Neuron::new(0, 0, 0, 1, 0, 0, 1, 1);
+        neuron.arp = Neuron::BASE_ABSOLUTE_REFRACTORY_PERIOD;
+        neuron.fr = 0.25;
+        let step = Neuron::ABSOLUTE_REFRACTORY_PERIOD_DECREASE_FACTOR * neuron.fr;
+
+        for calls in 1..=4 {
+            assert!(neuron.is_refractory(), "({}, {}, {}) -> Expected the neuron to be refractory before call {}", neuron.x, neuron.y, neuron.z, calls);
+            neuron.advance_refractory();
+            let expected = Neuron::BASE_ABSOLUTE_REFRACTORY_PERIOD - step * calls as f64;
+            assert!((neuron.arp - expected).abs() < 1e-12, "({}, {}, {}) -> Expected arp {} after {} calls, got {}", neuron.x, neuron.y, neuron.z, expected, calls, neuron.arp);
+        }
+        let before = neuron.arp;
+        assert!(neuron.is_refractory(), "({}, {}, {}) -> Expected the neuron to remain refractory at arp {}", neuron.x, neuron.y, neuron.z, neuron.arp);
+        assert_eq!(neuron.arp, before, "({}, {}, {}) -> Expected is_refractory to leave arp unchanged", neuron.x, neuron.y, neuron.z);
+
+        neuron.advance_refractory();
+        assert_eq!(neuron.arp, 0.0, "({}, {}, {}) -> Expected arp to clamp at zero", neuron.x, neuron.y, neuron.z);
+        assert!(!neuron.is_refractory(), "({}, {}, {}) -> Expected the refractory period to have ended", neuron.x, neuron.y, neuron.z);
+        neuron.advance_refractory();
+        assert_eq!(neuron.arp, 0.0, "({}, {}, {}) -> Expected advancing a non-refractory neuron to do nothing", neuron.x, neuron.y, neuron.z);
+    }
+
+    #[test]
+    fn test_frozen() {
+        let mut frozen = Neuron::new(0, 0, 0, 1, 0, 0, 1, 1);
+        let mut neighbor = Neuron::new(1, 0, 0, 2, 0, 0, 1, 1);
+        frozen.transmit_sync(30.0);
+        frozen.frozen = true;
+        let state = |n: &Neuron| (n.ap, n.mp, n.tp, n.fr, n.arp, n.rrp, n.sw, n.receptor_sensitivity, n.fire_count);
+        let before = state(&frozen);
+
+        frozen.transmit_sync(30.0);
+        frozen.recover_receptors();
+        neighbor.transmit_sync(30.0);
+        assert_eq!(state(&frozen), before, "({}, {}, {}) -> Expected the frozen neuron's state to be unchanged", frozen.x, frozen.y, frozen.z);
+        assert!(neighbor.ap > 0.0 && neighbor.mp > Neuron::RESTING_POTENTIAL, "({}, {}, {}) -> Expected the unfrozen neighbor to accumulate the input", neighbor.x, neighbor.y, neighbor.z);
+
+        let first = frozen.detect();
+        let second = frozen.detect();
+        assert!(first > 0.0 && first == second, "({}, {}, {}) -> Expected the frozen neuron to keep emitting its static signal, got {} then {}", frozen.x, frozen.y, frozen.z, first, second);
+        assert_eq!(state(&frozen), before, "({}, {}, {}) -> Expected firing to leave the frozen neuron unchanged", frozen.x, frozen.y, frozen.z);
+    }
+
+    #[test]
+    fn test_conductance_based_inhibition() {
+        let mut at_rest = Neuron::new(0, 0, 0, 1, 0, 0, 0, 1);
+        at_rest.params.conductance_based = true;
+        let mut hyperpolarized = at_rest.clone();
+        hyperpolarized.ap = -8.0;
+        hyperpolarized.mp = Neuron::RESTING_POTENTIAL + hyperpolarized.ap; // 2 mV above the inhibitory reversal potential
+
+        let (rest_before, hyper_before) = (at_rest.ap, hyperpolarized.ap);
+        at_rest.transmit_sync(-5.0);
+        hyperpolarized.transmit_sync(-5.0);
+        let rest_change = at_rest.ap - rest_before;
+        let hyper_change = hyperpolarized.ap - hyper_before;
+        assert!(rest_change < 0.0 && hyper_change < 0.0, "({}, {}, {}) -> Expected inhibition to lower both potentials, got {} and {}", at_rest.x, at_rest.y, at_rest.z, rest_change, hyper_change);
+        assert!(hyper_change.abs() < rest_change.abs(), "({}, {}, {}) -> Expected weaker inhibition near the reversal potential, got {} versus {}", hyperpolarized.x, hyperpolarized.y, hyperpolarized.z, hyper_change, rest_change);
+
+        let mut current = Neuron::new(0, 0, 0, 1, 0, 0, 0, 1);
+        current.transmit_sync(-5.0);
+        assert!((current.ap - rest_change).abs() < 1e-12, "({}, {}, {}) -> Expected a conductance at rest to act like the equivalent current, got {} versus {}", current.x, current.y, current.z, rest_change, current.ap);
+    }
+
+    #[test]
+    fn test_signal_to_noise_ratio() {
+        let mut neuron = Neuron::new(0, 0, 0, 1, 0, 0, 1, 1);
+        neuron.mp = neuron.tp + 4.0;
+        assert_eq!(neuron.signal_to_noise_ratio(), f64::INFINITY, "({}, {}, {}) -> Expected an infinite ratio without noise", neuron.x, neuron.y, neuron.z);
+
+        neuron.params.noise_std = 2.0;
+        assert_eq!(neuron.signal_to_noise_ratio(), 2.0, "({}, {}, {}) -> Expected a margin of 4 over noise of 2", neuron.x, neuron.y, neuron.z);
+        neuron.mp = neuron.tp + 8.0;
+        assert_eq!(neuron.signal_to_noise_ratio(), 4.0, "({}, {}, {}) -> Expected the ratio to double with the margin", neuron.x, neuron.y, neuron.z);
+        neuron.params.noise_std = 8.0;
+        assert_eq!(neuron.signal_to_noise_ratio(), 1.0, "({}, {}, {}) -> Expected the ratio to fall as the noise grows", neuron.x, neuron.y, neuron.z);
+        neuron.mp = neuron.tp - 4.0;
+        assert_eq!(neuron.signal_to_noise_ratio(), -0.5, "({}, {}, {}) -> Expected a negative ratio below threshold", neuron.x, neuron.y, neuron.z);
+    }
+
+    #[test]
+    fn test_coord3() {
+        let a = Coord3::new(1, 2, 3);
+        let b = Coord3::from((4, 6, 3));
+        assert_eq!(a.abs_diff(b), Coord3::new(3, 4, 0), "({}, {}, {}) -> Expected per-axis distances", a.x, a.y, a.z);
+        assert_eq!(b.abs_diff(a), a.abs_diff(b), "({}, {}, {}) -> Expected abs_diff to be symmetric without underflow", b.x, b.y, b.z);
+        #[cfg(feature = "std")]
+        assert_eq!(a.distance_to(b), 5.0, "({}, {}, {}) -> Expected a Euclidean distance of 5", a.x, a.y, a.z);
+
+        assert_eq!(a.offset(2, -2, 0), Some(Coord3::new(3, 0, 3)), "({}, {}, {}) -> Expected the offset coordinate", a.x, a.y, a.z);
+        assert_eq!(a.offset(-2, 0, 0), None, "({}, {}, {}) -> Expected an offset below zero to be rejected", a.x, a.y, a.z);
+
+        let tuple: (usize, usize, usize) = a.into();
+        assert_eq!((tuple, Coord3::from(tuple)), ((1, 2, 3), a), "({}, {}, {}) -> Expected a lossless round trip through a tuple", a.x, a.y, a.z);
+        let neuron = Neuron::new(1, 2, 3, 4, 6, 3, 0, 1);
+        assert_eq!((neuron.position(), neuron.axon_position()), (a, b), "({}, {}, {}) -> Expected the neuron and axon coordinates", neuron.x, neuron.y, neuron.z);
+        assert_eq!(neuron.connection_direction(a), neuron.connection_direction((1, 2, 3)), "({}, {}, {}) -> Expected a Coord3 and a tuple to be interchangeable", neuron.x, neuron.y, neuron.z);
+
+        #[cfg(feature = "std")]
+        {
+            let far = Coord3::new(usize::MAX, 0, 0);
+            let origin = Coord3::default();
+            assert_eq!(far.distance_to(origin), usize::MAX as f64, "({}, {}, {}) -> Expected a large distance without overflow", far.x, far.y, far.z);
+        }
+    }
+
+    #[test]
+    fn test_adaptation_index() {
+        let mut neuron = Neuron::new(0, 0, 0, 1, 0, 0, 1, 1);
+        assert_eq!(neuron.adaptation_index(), 0.0, "({}, {}, {}) -> Expected no adaptation at baseline", neuron.x, neuron.y, neuron.z);
+        assert!(!neuron.is_adapting(), "({}, {}, {}) -> Expected a fresh neuron not to be adapting", neuron.x, neuron.y, neuron.z);
+        neuron.params.cumulative_threshold_adaptation = true;
+
+        let mut indices = Vec::new();
+        for _ in 0..200 {
+            neuron.transmit_sync(30.0);
+            neuron.detect();
+            indices.push(neuron.adaptation_index());
+        }
+        let last = *indices.last().unwrap();
+        assert!(neuron.is_adapting(), "({}, {}, {}) -> Expected sustained firing to cause adaptation", neuron.x, neuron.y, neuron.z);
+        let peak = indices.iter().copied().fold(0.0, f64::max);
+        assert!(indices[..10].windows(2).all(|pair| pair[0] < pair[1]), "({}, {}, {}) -> Expected the index to rise while firing is sustained, got {:?}", neuron.x, neuron.y, neuron.z, &indices[..10]);
+        assert!(peak > 0.99 && last > 0.5, "({}, {}, {}) -> Expected the index to approach 1 and stay high, got peak {} and final {}", neuron.x, neuron.y, neuron.z, peak, last);
+    }
+
+    #[test]
+    fn test_detect_binary() {
+        let mut graded = Neuron::new(0, 0, 0, 1, 0, 0, 1, 1);
+        let mut binary = graded.clone();
+        for (step, input) in [0.0, 5.0, 30.0, 0.0, 30.0, 30.0, 2.0].into_iter().enumerate() {
+            graded.transmit_sync(input);
+            binary.transmit_sync(input);
+            let fired = binary.detect_binary();
+            let signal = graded.detect();
+            assert_eq!(fired, signal != 0.0, "({}, {}, {}) -> Expected detect_binary to match detect at step {}, got {} against {}", binary.x, binary.y, binary.z, step, fired, signal);
+            assert_eq!(binary.ap, graded.ap, "({}, {}, {}) -> Expected identical accumulated potential at step {}", binary.x, binary.y, binary.z, step);
+            if fired {
+                assert_eq!(binary.ap, 0.0, "({}, {}, {}) -> Expected firing to reset the accumulated potential", binary.x, binary.y, binary.z);
+            }
+        }
+        assert!(binary.fire_count > 0, "({}, {}, {}) -> Expected the sequence to include a spike", binary.x, binary.y, binary.z);
+    }
+
+    #[test]
+    fn test_drive_balance() {
+        let mut neuron = Neuron::new(0, 0, 0, 1, 0, 0, 1, 1);
+        for input in [4.0, -1.5, 2.5, -3.0, 1.0] {
+            neuron.transmit_sync(input);
+        }
+        assert_eq!(neuron.drive_balance(), (7.5, -4.5), "({}, {}, {}) -> Expected the positive and negative input sums", neuron.x, neuron.y, neuron.z);
+        assert_eq!(neuron.detect(), 0.0, "({}, {}, {}) -> Expected the neuron to stay below threshold", neuron.x, neuron.y, neuron.z);
+
+        neuron.transmit_sync(30.0);
+        assert!(neuron.detect() > 0.0, "({}, {}, {}) -> Expected a strong input to fire the neuron", neuron.x, neuron.y, neuron.z);
+        assert_eq!(neuron.drive_balance(), (0.0, 0.0), "({}, {}, {}) -> Expected firing to reset the drives along with ap", neuron.x, neuron.y, neuron.z);
+    }
+}
@@ -0,0 +1,52 @@
+// tests/test_deterministic.rs
+#![cfg(feature = "deterministic")]
+use neuron::Neuron;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sorted_connection_iteration() {
+        let mut neuron = Neuron::new(5, 5, 5, 6, 6, 6, 0, 1);
+        let mut partners: Vec<_> = [(9, 0, 0), (0, 3, 1), (0, 3, 0), (2, 0, 7), (7, 7, 7)].iter().map(|&(x, y, z)| Neuron::new(x, y, z, x, y, z + 1, 0, 1)).collect();
+        for partner in partners.iter_mut() {
+            neuron.establish_axonal_connection(partner).unwrap();
+            partner.establish_axonal_connection(&mut neuron).unwrap();
+        }
+
+        let axonal: Vec<_> = neuron.ac.iter().copied().collect();
+        let dendritic: Vec<_> = neuron.dc.iter().copied().collect();
+        let expected = vec![(0, 3, 0), (0, 3, 1), (2, 0, 7), (7, 7, 7), (9, 0, 0)];
+        assert_eq!(axonal, expected, "({}, {}, {}) -> Expected axonal connections to iterate in sorted order", neuron.x, neuron.y, neuron.z);
+        assert_eq!(dendritic, expected, "({}, {}, {}) -> Expected dendritic connections to iterate in sorted order", neuron.x, neuron.y, neuron.z);
+    }
+
+    #[test]
+    fn test_connection_operations() {
+        let mut neuron1 = Neuron::new(1, 2, 3, 2, 3, 4, 0, 1);
+        let mut neuron2 = Neuron::new(4, 5, 6, 5, 6, 7, 0, 1);
+
+        neuron1.establish_axonal_connection(&mut neuron2).unwrap();
+        assert!(neuron1.ac.contains(&(4, 5, 6)) && neuron2.dc.contains(&(1, 2, 3)), "({}, {}, {}) -> Expected the axonal connection to be established", neuron1.x, neuron1.y, neuron1.z);
+        neuron1.terminate_axonal_connection(&mut neuron2);
+        assert!(neuron1.ac.is_empty() && neuron2.dc.is_empty(), "({}, {}, {}) -> Expected the axonal connection to be terminated", neuron1.x, neuron1.y, neuron1.z);
+
+        neuron2.establish_dendritic_connection(&mut neuron1).unwrap();
+        assert!(neuron2.dc.contains(&(1, 2, 3)) && neuron1.ac.contains(&(4, 5, 6)), "({}, {}, {}) -> Expected the dendritic connection to be established", neuron2.x, neuron2.y, neuron2.z);
+        neuron2.terminate_dendritic_connection(&mut neuron1);
+        assert!(neuron2.dc.is_empty() && neuron1.ac.is_empty(), "({}, {}, {}) -> Expected the dendritic connection to be terminated", neuron2.x, neuron2.y, neuron2.z);
+
+        neuron1.sw = 0.05;
+        neuron1.sst = 0.05;
+        neuron2.sw = 0.1;
+        neuron1.establish_axonal_connection(&mut neuron2).unwrap();
+        neuron1.prune_axonal_connection(&mut neuron2);
+        assert!(!neuron1.ac.contains(&(4, 5, 6)) && !neuron2.dc.contains(&(1, 2, 3)), "({}, {}, {}) -> Expected the weak connection to be pruned", neuron1.x, neuron1.y, neuron1.z);
+
+        neuron2.sst = 0.1;
+        neuron1.establish_axonal_connection(&mut neuron2).unwrap();
+        neuron2.prune_dendritic_connection(&mut neuron1);
+        assert!(!neuron2.dc.contains(&(1, 2, 3)) && !neuron1.ac.contains(&(4, 5, 6)), "({}, {}, {}) -> Expected the weak dendritic connection to be pruned", neuron2.x, neuron2.y, neuron2.z);
+    }
+}
@@ -0,0 +1,40 @@
+// tests/test_heatmap.rs
+#![cfg(feature = "std")]
+use neuron::{Axis, Network, Neuron};
+
+// Builds a small network with two neurons stacked along z and one firing neuron off to the side.
+fn tiny_network() -> Network {
+    let mut network = Network::new();
+    let low = network.add_neuron(Neuron::new(0, 0, 0, 0, 0, 1, 0, 1));
+    let high = network.add_neuron(Neuron::new(0, 0, 2, 0, 0, 3, 0, 1));
+    let side = network.add_neuron(Neuron::new(2, 1, 0, 2, 1, 1, 0, 1));
+    network.neuron_mut(low).fr = 0.2;
+    network.neuron_mut(high).fr = 0.6;
+    network.neuron_mut(side).fr = 1.0;
+    network
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_activity_heatmap() {
+        let grid = tiny_network().activity_heatmap(Axis::Z);
+        assert_eq!((grid.len(), grid[0].len()), (2, 3), "Expected a 2 x 3 grid, got {:?}", grid);
+        assert_eq!(grid[0][0], Some(0.6), "Expected the stacked cell to hold the highest firing rate, got {:?}", grid);
+        assert_eq!(grid[1][2], Some(1.0), "Expected the side neuron's firing rate, got {:?}", grid);
+        assert_eq!(grid[0][1], None, "Expected an empty cell, got {:?}", grid);
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn test_render_activity_png() {
+        let path = std::env::temp_dir().join(format!("neuron_{}_activity.png", std::process::id()));
+        tiny_network().render_activity_png(Axis::Y, &path).unwrap();
+
+        let (width, height) = image::image_dimensions(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(width > 0 && height > 0, "Expected nonzero image dimensions, got {} x {}", width, height);
+    }
+}
@@ -0,0 +1,43 @@
+// tests/test_logging.rs
+#![cfg(all(feature = "log", feature = "std"))]
+use neuron::Neuron;
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use std::sync::Mutex;
+
+struct CapturingLogger {
+    records: Mutex<Vec<(Level, String)>>,
+}
+
+impl Log for CapturingLogger {
+    fn enabled(&self, _metadata: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        self.records.lock().unwrap().push((record.level(), record.args().to_string()));
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: CapturingLogger = CapturingLogger { records: Mutex::new(Vec::new()) };
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_fire_emits_trace_record() {
+        log::set_logger(&LOGGER).unwrap();
+        log::set_max_level(LevelFilter::Trace);
+
+        let mut neuron = Neuron::new(1, 2, 3, 2, 3, 4, 1, 1);
+        neuron.transmit(20.0, None).await;
+        let output = neuron.detect();
+        assert!(output > 0.0, "({}, {}, {}) -> Expected neuron to fire, got {}", neuron.x, neuron.y, neuron.z, output);
+
+        let records = LOGGER.records.lock().unwrap();
+        let expected = format!("(1, 2, 3) -> fire: signal {}", output);
+        assert!(records.iter().any(|(level, message)| *level == Level::Trace && *message == expected), "Expected a trace record \"{}\", got {:?}", expected, records);
+    }
+}
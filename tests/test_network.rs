@@ -0,0 +1,1217 @@
+// tests/test_network.rs
+#![cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+use neuron::{isi_statistics, spike_cross_correlation, FiringSnapshot, LayerSpec, Network, Neuron, NeuronError, Neurotransmitter};
+#[cfg(feature = "rand")]
+use rand::{rngs::SmallRng, SeedableRng};
+
+// Asserts that every connection has a matching back-reference and that the coordinate index is accurate.
+fn assert_consistent(network: &Network) {
+    for (id, neuron) in network.neurons().iter().enumerate() {
+        let pos = (neuron.x, neuron.y, neuron.z);
+        assert_eq!(network.id_of(pos), Some(id), "({}, {}, {}) -> Expected the index to map to neuron {}", neuron.x, neuron.y, neuron.z, id);
+        for &target in &neuron.ac {
+            let partner = network.id_of(target).map(|t| network.neuron(t));
+            assert!(partner.is_some_and(|p| p.dc.contains(&pos)), "({}, {}, {}) -> Expected {:?} to hold a dendritic back-reference", neuron.x, neuron.y, neuron.z, target);
+        }
+        for &source in &neuron.dc {
+            let partner = network.id_of(source).map(|s| network.neuron(s));
+            assert!(partner.is_some_and(|p| p.ac.contains(&pos)), "({}, {}, {}) -> Expected {:?} to hold an axonal back-reference", neuron.x, neuron.y, neuron.z, source);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bfs_from_layers() {
+        let mut network = Network::new();
+        let input = network.add_neuron(Neuron::new(0, 0, 0, 1, 0, 0, 1, 1));
+        let hidden1 = network.add_neuron(Neuron::new(1, 0, 0, 2, 0, 0, 0, 1));
+        let hidden2 = network.add_neuron(Neuron::new(1, 1, 0, 2, 1, 0, 0, 1));
+        let output = network.add_neuron(Neuron::new(2, 0, 0, 3, 0, 0, 2, 1));
+        let unreachable = network.add_neuron(Neuron::new(5, 5, 5, 6, 6, 6, 0, 1));
+
+        network.connect(input, hidden1).unwrap();
+        network.connect(input, hidden2).unwrap();
+        network.connect(hidden1, output).unwrap();
+        network.connect(hidden2, output).unwrap();
+        network.connect(output, input).unwrap(); // Back edge must not revisit the source
+
+        let levels = network.bfs_from(input);
+        assert_eq!(levels, vec![vec![input], vec![hidden1, hidden2], vec![output]], "Expected BFS levels to match the layer structure, got {:?}", levels);
+        assert!(!levels.iter().flatten().any(|&id| id == unreachable), "Expected neuron {} to be unreachable, got {:?}", unreachable, levels);
+    }
+
+    #[test]
+    fn test_path_length_metrics() {
+        let mut network = Network::new();
+        let ids: Vec<_> = (0..4).map(|x| network.add_neuron(Neuron::new(x, 0, 0, x + 1, 0, 0, 0, 1))).collect();
+        assert_eq!((network.average_path_length(), network.diameter()), (None, None), "Expected no metrics without connections");
+
+        // A chain 0 -> 1 -> 2 -> 3 with a shortcut 0 -> 2
+        network.connect(ids[0], ids[1]).unwrap();
+        network.connect(ids[1], ids[2]).unwrap();
+        network.connect(ids[2], ids[3]).unwrap();
+        network.connect(ids[0], ids[2]).unwrap();
+        network.add_neuron(Neuron::new(9, 9, 9, 9, 9, 8, 0, 1)); // Isolated neurons contribute no pairs
+
+        // Reachable pairs: 0->1 (1), 0->2 (1), 0->3 (2), 1->2 (1), 1->3 (2), 2->3 (1)
+        let average = network.average_path_length().unwrap();
+        assert!((average - 8.0 / 6.0).abs() < 1e-12, "Expected an average path length of 8/6, got {}", average);
+        assert_eq!(network.diameter(), Some(2), "Expected a diameter of 2");
+    }
+
+    #[test]
+    fn test_lateral_inhibition() {
+        let mut network = Network::new();
+        let winner = network.add_neuron(Neuron::new(1, 1, 0, 1, 1, 1, 0, 1));
+        let left = network.add_neuron(Neuron::new(0, 1, 0, 0, 1, 1, 0, 1));
+        let right = network.add_neuron(Neuron::new(2, 1, 0, 2, 1, 1, 0, 1));
+        let distant = network.add_neuron(Neuron::new(9, 9, 0, 9, 9, 1, 0, 1));
+        network.inject_current(30.0, Some(&[winner]));
+        network.inject_current(5.0, Some(&[left, right, distant]));
+        let before: Vec<f64> = network.neurons().iter().map(|neuron| neuron.ap).collect();
+        assert!(network.neuron(winner).will_fire(), "Expected the driven neuron to be above threshold");
+
+        network.apply_lateral_inhibition(1.5, 0.1);
+        for id in [left, right] {
+            let neuron = network.neuron(id);
+            assert!(neuron.ap < before[id], "({}, {}, {}) -> Expected the neighbor's accumulated potential to decrease from {}, got {}", neuron.x, neuron.y, neuron.z, before[id], neuron.ap);
+        }
+        assert_eq!(network.neuron(winner).ap, before[winner], "Expected the firer to keep its accumulated potential");
+        assert_eq!(network.neuron(distant).ap, before[distant], "Expected neurons beyond the radius to be unaffected");
+        assert!(network.neuron(winner).will_fire(), "Expected the firer to remain above threshold");
+    }
+
+    #[test]
+    fn test_firing_snapshot() {
+        let mut network = Network::new();
+        let ids: Vec<_> = (0..4).map(|x| network.add_neuron(Neuron::new(x, 0, 0, x + 1, 0, 0, 0, 1))).collect();
+        network.inject_current(30.0, Some(&[ids[1], ids[3]]));
+        let before: Vec<f64> = network.neurons().iter().map(|neuron| neuron.ap).collect();
+
+        let snapshot = network.firing_snapshot();
+        let expected: HashMap<_, _> = ids.iter().map(|&id| (id, id == ids[1] || id == ids[3])).collect();
+        assert_eq!(snapshot, expected, "Expected only the driven neurons above threshold, got {:?}", snapshot);
+        assert_eq!(network.firing_snapshot(), snapshot, "Expected polling again to report the same state");
+        let after: Vec<f64> = network.neurons().iter().map(|neuron| neuron.ap).collect();
+        assert_eq!(after, before, "Expected the snapshot to leave every accumulated potential unchanged");
+    }
+
+    #[test]
+    fn test_weight_delta_since() {
+        let mut network = Network::new();
+        let ids: Vec<_> = (0..3).map(|x| network.add_neuron(Neuron::new(x, 0, 0, x + 1, 0, 0, 0, 1))).collect();
+        network.connect(ids[0], ids[1]).unwrap();
+        network.connect(ids[1], ids[2]).unwrap();
+        for &id in &ids {
+            network.neuron_mut(id).sw = 0.5; // Leave room below the weight ceiling for learning
+        }
+        let snapshot = network.snapshot_weights();
+        let before: Vec<f64> = network.neurons().iter().map(|neuron| neuron.sw).collect();
+        assert_eq!(network.weight_delta_since(&snapshot), 0.0, "Expected no change right after the snapshot");
+
+        for _ in 0..10 {
+            network.inject_current(30.0, Some(&ids[..1]));
+            network.tick();
+        }
+
+        let expected: f64 = network.neurons().iter().zip(&before).map(|(neuron, sw)| (neuron.sw - sw).abs()).sum();
+        let delta = network.weight_delta_since(&snapshot);
+        assert!(delta > 0.0, "Expected training to change the weights, got {}", delta);
+        assert!((delta - expected).abs() < 1e-12, "Expected the delta {} to match the summed per-neuron changes {}", delta, expected);
+    }
+
+    #[test]
+    fn test_run_with_input() {
+        let mut network = Network::new();
+        let ids: Vec<_> = (0..5).map(|x| network.add_neuron(Neuron::new(x, 0, 0, x, 0, 1, 0, 1))).collect();
+        let ramp = |tick: u64| ids.iter().map(|&id| (id, tick as f64 * 0.5)).collect::<HashMap<_, _>>();
+
+        let snapshots: Vec<FiringSnapshot> = network.run_with_input(40, ramp);
+        let ticks: Vec<u64> = snapshots.iter().map(|snapshot| snapshot.tick).collect();
+        assert_eq!(ticks, (0..40).collect::<Vec<_>>(), "Expected one snapshot per tick");
+        let fires = |range: std::ops::Range<usize>| snapshots[range].iter().map(|snapshot| snapshot.fired.len()).sum::<usize>();
+        let (early, late) = (fires(0..20), fires(20..40));
+        assert!(late > early, "Expected more firing as the ramp grows, got {} early against {} late", early, late);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_run_with_input_skips_dropped() {
+        let mut network = Network::new();
+        let ids: Vec<_> = (0..2).map(|x| network.add_neuron(Neuron::new(x, 0, 0, x, 0, 1, 0, 1))).collect();
+        let constant = |_: u64| ids.iter().map(|&id| (id, 20.0)).collect::<HashMap<_, _>>();
+
+        let dropped = network.apply_dropout(1.0, &mut SmallRng::seed_from_u64(0));
+        let snapshots = network.run_with_input(1, constant);
+        assert_eq!(dropped.len(), network.len(), "Expected every neuron to be dropped");
+        assert!(snapshots[0].fired.is_empty(), "Expected no dropped neuron to fire, got {:?}", snapshots[0].fired);
+        assert!(network.neurons().iter().all(|n| n.ap == 0.0), "Expected dropped neurons not to receive the input");
+
+        let snapshots = network.run_with_input(2, constant);
+        assert_eq!(snapshots[1].fired.len(), network.len(), "Expected the input to be delivered once the dropout has passed, got {:?}", snapshots[1].fired);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_batch_stimulate_parallel() {
+        let mut network = Network::new();
+        let ids: Vec<_> = (0..64).map(|x| network.add_neuron(Neuron::new(x, 0, 0, x, 0, 1, 0, (x % 2) as u32))).collect();
+        let stimuli: Vec<(usize, f64)> = ids.iter().flat_map(|&id| [(id, (id % 7) as f64 * 4.0), (id, -((id % 3) as f64))]).collect();
+
+        let mut sequential = network.clone();
+        for &(id, input) in &stimuli {
+            sequential.neuron_mut(id).transmit_sync(input);
+        }
+        network.batch_stimulate_parallel(&stimuli);
+
+        for (parallel, expected) in network.neurons().iter().zip(sequential.neurons()) {
+            let state = |n: &Neuron| (n.ap, n.mp, n.tp, n.fr, n.sw, n.sst, n.pr, n.ltp, n.ltd, n.arp, n.rrp);
+            assert_eq!(state(parallel), state(expected), "({}, {}, {}) -> Expected the parallel state to match the sequential one", parallel.x, parallel.y, parallel.z);
+        }
+    }
+
+    #[test]
+    fn test_inject_spike_train() {
+        let mut network = Network::new();
+        let target = network.add_neuron(Neuron::new(0, 0, 0, 1, 0, 0, 0, 1));
+        network.inject_spike_train(target, &[(0, 2.0), (3, 3.0)]);
+
+        let mut received = Vec::new();
+        for tick in 0..6 {
+            let before = network.neuron(target).ap;
+            network.tick();
+            let change = network.neuron(target).ap - before;
+            if change != 0.0 {
+                received.push((tick, change));
+            }
+        }
+
+        let ticks: Vec<u64> = received.iter().map(|&(tick, _)| tick).collect();
+        assert_eq!(ticks, vec![0, 3], "Expected inputs at exactly ticks 0 and 3, got {:?}", received);
+        let ratio = received[1].1 / received[0].1;
+        assert!((ratio - 1.5).abs() < 1e-9, "Expected the inputs to arrive in proportion 3 : 2, got {:?}", received);
+    }
+
+    #[test]
+    fn test_reachable_from() {
+        let mut network = Network::new();
+        let input1 = network.add_neuron(Neuron::new(0, 0, 0, 1, 0, 0, 1, 1));
+        let input2 = network.add_neuron(Neuron::new(0, 1, 0, 1, 1, 0, 1, 1));
+        let hidden = network.add_neuron(Neuron::new(1, 0, 0, 2, 0, 0, 0, 1));
+        let output = network.add_neuron(Neuron::new(2, 0, 0, 3, 0, 0, 2, 1));
+        let side = network.add_neuron(Neuron::new(1, 1, 0, 2, 1, 0, 0, 1));
+        let isolated = network.add_neuron(Neuron::new(5, 5, 5, 6, 6, 6, 0, 1));
+        let upstream = network.add_neuron(Neuron::new(4, 4, 4, 5, 4, 4, 0, 1));
+
+        network.connect(input1, hidden).unwrap();
+        network.connect(hidden, output).unwrap();
+        network.connect(input2, side).unwrap();
+        network.connect(upstream, input1).unwrap(); // Edges into a source do not make their origin reachable
+
+        let reached = network.reachable_from(&[input1, input2]);
+        assert_eq!(reached, HashSet::from([input1, input2, hidden, output, side]), "Expected the neurons downstream of either source, got {:?}", reached);
+        assert!(!reached.contains(&isolated), "Expected the isolated neuron {} to be unreachable", isolated);
+        assert!(!reached.contains(&upstream), "Expected the upstream neuron {} to be unreachable", upstream);
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        let build = |order: &[usize]| {
+            let specs = [(0, 0, 0, 1), (1, 0, 0, 0), (2, 0, 0, 2)];
+            let mut network = Network::new();
+            for &i in order {
+                let (x, y, z, nt) = specs[i];
+                network.add_neuron(Neuron::new(x, y, z, x + 1, y, z, nt, 1));
+            }
+            let id = |network: &Network, i: usize| network.id_of((specs[i].0, specs[i].1, specs[i].2)).unwrap();
+            let (a, b, c) = (id(&network, 0), id(&network, 1), id(&network, 2));
+            network.connect(a, b).unwrap();
+            network.connect(a, c).unwrap();
+            network.connect(b, c).unwrap();
+            network
+        };
+
+        let forward = build(&[0, 1, 2]);
+        let reversed = build(&[2, 1, 0]);
+        assert_eq!(forward.fingerprint(), reversed.fingerprint(), "({}, {}) -> Expected identical fingerprints regardless of insertion order", forward.fingerprint(), reversed.fingerprint());
+
+        let mut changed = build(&[0, 1, 2]);
+        let id = changed.id_of((1, 0, 0)).unwrap();
+        changed.neuron_mut(id).sw += 0.1;
+        assert_ne!(forward.fingerprint(), changed.fingerprint(), "({}) -> Expected a weight change to alter the fingerprint", forward.fingerprint());
+    }
+
+    #[test]
+    fn test_all_simple_paths() {
+        let mut network = Network::new();
+        let input = network.add_neuron(Neuron::new(0, 0, 0, 1, 0, 0, 1, 1));
+        let short = network.add_neuron(Neuron::new(1, 0, 0, 2, 0, 0, 0, 1));
+        let long1 = network.add_neuron(Neuron::new(1, 1, 0, 2, 1, 0, 0, 1));
+        let long2 = network.add_neuron(Neuron::new(2, 1, 0, 3, 1, 0, 0, 1));
+        let output = network.add_neuron(Neuron::new(3, 0, 0, 4, 0, 0, 2, 1));
+
+        network.connect(input, short).unwrap();
+        network.connect(short, output).unwrap();
+        network.connect(input, long1).unwrap();
+        network.connect(long1, long2).unwrap();
+        network.connect(long2, output).unwrap();
+        network.connect(output, input).unwrap(); // Cycle must not produce repeating paths
+
+        let paths = network.all_simple_paths(input, output, 5);
+        assert_eq!(paths, vec![vec![input, short, output], vec![input, long1, long2, output]], "Expected both routes, got {:?}", paths);
+
+        let bounded = network.all_simple_paths(input, output, 2);
+        assert_eq!(bounded, vec![vec![input, short, output]], "Expected max_len to exclude the three-hop route, got {:?}", bounded);
+        assert!(network.all_simple_paths(output, long2, 1).is_empty(), "Expected no path within a single hop");
+    }
+
+    #[test]
+    fn test_global_modulation() {
+        let mut baseline = Network::new();
+        for i in 0..3 {
+            baseline.add_neuron(Neuron::new(i, 0, 0, i, 1, 0, 1, 1));
+        }
+        let mut modulated = baseline.clone();
+        modulated.set_global_modulation(0.25);
+
+        baseline.inject_current(15.0, None);
+        modulated.inject_current(15.0, None);
+        for (id, (base, low)) in baseline.neurons().iter().zip(modulated.neurons()).enumerate() {
+            assert!(low.ap < base.ap, "Expected neuron {} to accumulate less under low modulation, got {} against {}", id, low.ap, base.ap);
+        }
+
+        modulated.set_global_modulation(10.0);
+        assert!(modulated.neurons().iter().all(|neuron| neuron.nc == Neuron::MAX_NEUROTRANSMITTER_CONCENTRATION), "Expected the concentration to be clamped");
+
+        modulated.modulate_region(((1, 0, 0), (2, 0, 0)), 0.5);
+        let concentrations: Vec<f64> = modulated.neurons().iter().map(|neuron| neuron.nc).collect();
+        assert_eq!(concentrations, vec![Neuron::MAX_NEUROTRANSMITTER_CONCENTRATION, 0.5, 0.5], "Expected only the region to be modulated, got {:?}", concentrations);
+    }
+
+    #[test]
+    fn test_partition() {
+        let mut network = Network::new();
+        let exc1 = network.add_neuron(Neuron::new(0, 0, 0, 1, 0, 0, 0, 1));
+        let inh1 = network.add_neuron(Neuron::new(1, 0, 0, 2, 0, 0, 0, 0));
+        let exc2 = network.add_neuron(Neuron::new(2, 0, 0, 3, 0, 0, 0, 1));
+        let inh2 = network.add_neuron(Neuron::new(3, 0, 0, 4, 0, 0, 0, 0));
+        network.connect(exc1, exc2).unwrap();
+        network.connect(inh1, inh2).unwrap();
+        network.connect(exc1, inh1).unwrap();
+        network.connect(inh2, exc2).unwrap();
+        network.connect(exc2, inh2).unwrap();
+
+        let (excitatory, inhibitory, cut) = network.partition(|neuron| neuron.nrt == 1);
+        assert_eq!(cut, vec![(exc1, inh1), (exc2, inh2), (inh2, exc2)], "Expected exactly the inter-type connections to be cut, got {:?}", cut);
+        assert_consistent(&excitatory);
+        assert_consistent(&inhibitory);
+        assert!(excitatory.neurons().iter().all(|neuron| neuron.nrt == 1) && excitatory.len() == 2, "Expected the excitatory side to hold both excitatory neurons");
+        assert!(inhibitory.neurons().iter().all(|neuron| neuron.nrt == 0) && inhibitory.len() == 2, "Expected the inhibitory side to hold both inhibitory neurons");
+        assert_eq!(excitatory.targets(0), vec![1], "Expected the excitatory connection to be preserved");
+        assert_eq!(inhibitory.targets(0), vec![1], "Expected the inhibitory connection to be preserved");
+        assert!(excitatory.targets(1).is_empty() && inhibitory.targets(1).is_empty(), "Expected no connections across the sides");
+        assert_eq!(network.len(), 4, "Expected the source network to be unchanged");
+    }
+
+    #[test]
+    fn test_connect_if() {
+        let mut network = Network::new();
+        let exc_sensory = network.add_neuron(Neuron::new(0, 0, 0, 1, 0, 0, 1, 1));
+        let exc_motor = network.add_neuron(Neuron::new(1, 0, 0, 2, 0, 0, 2, 1));
+        let inh_contact = network.add_neuron(Neuron::new(2, 0, 0, 3, 0, 0, 0, 0));
+        let inh_motor = network.add_neuron(Neuron::new(3, 0, 0, 4, 0, 0, 2, 0));
+
+        network.connect_if(|a, b| a.nrt == 1 && b.is_motor());
+        assert_consistent(&network);
+        let edges: Vec<(usize, usize)> = (0..network.len()).flat_map(|from| network.targets(from).into_iter().map(move |to| (from, to))).collect();
+        assert_eq!(edges, vec![(exc_sensory, exc_motor), (exc_sensory, inh_motor), (exc_motor, inh_motor)], "Expected excitatory-to-motor edges only, without self-loops, got {:?}", edges);
+        assert!(network.targets(inh_contact).is_empty(), "Expected the inhibitory neurons to gain no connections");
+    }
+
+    #[test]
+    fn test_component_sizes() {
+        let mut network = Network::new();
+        assert_eq!(network.largest_component_fraction(), 0.0, "Expected no fraction for an empty network");
+        let ids: Vec<_> = (0..4).map(|x| network.add_neuron(Neuron::new(x, 0, 0, x + 1, 0, 0, 0, 1))).collect();
+        network.connect(ids[0], ids[1]).unwrap();
+        network.connect(ids[2], ids[1]).unwrap(); // Joined through a shared target, so direction is ignored
+
+        assert_eq!(network.component_sizes(), vec![3, 1], "Expected a component of three and an isolated neuron");
+        assert_eq!(network.largest_component_fraction(), 0.75, "Expected three of four neurons in the largest component");
+    }
+
+    #[test]
+    fn test_remove_self_loops() {
+        let mut network = Network::new();
+        let a = network.add_neuron(Neuron::new(0, 0, 0, 1, 0, 0, 0, 1));
+        let b = network.add_neuron(Neuron::new(1, 0, 0, 2, 0, 0, 0, 1));
+        network.connect(a, b).unwrap();
+        // Bypass the connection guard, as an edited checkpoint might
+        network.neuron_mut(b).ac.insert((1, 0, 0));
+        network.neuron_mut(b).dc.insert((1, 0, 0));
+
+        assert_eq!(network.remove_self_loops(), 1, "Expected one self-loop to be removed");
+        assert_consistent(&network);
+        assert!(!network.neuron(b).ac.contains(&(1, 0, 0)) && !network.neuron(b).dc.contains(&(1, 0, 0)), "Expected the self-loop to be gone");
+        assert_eq!(network.targets(a), vec![b], "Expected other connections to be kept");
+        assert_eq!(network.remove_self_loops(), 0, "Expected nothing left to remove");
+    }
+
+    #[test]
+    fn test_clone_region() {
+        let mut network = Network::new();
+        for x in 0..3 {
+            for y in 0..3 {
+                network.add_neuron(Neuron::new(x, y, 0, x, y, 1, 0, 1));
+            }
+        }
+        for from in 0..network.len() {
+            for to in 0..network.len() {
+                if from != to && (from + to) % 3 == 0 {
+                    network.connect(from, to).unwrap();
+                }
+            }
+        }
+        let before = network.to_adjacency_matrix();
+
+        let bounds = ((0, 0, 0), (1, 1, 0));
+        let region = network.clone_region(bounds);
+        let positions: Vec<_> = region.neurons().iter().map(|n| (n.x, n.y, n.z)).collect();
+        assert_eq!(positions, vec![(0, 0, 0), (0, 1, 0), (1, 0, 0), (1, 1, 0)], "Expected only in-bounds neurons, got {:?}", positions);
+        assert_consistent(&region);
+
+        for (id, neuron) in region.neurons().iter().enumerate() {
+            let original = network.id_of((neuron.x, neuron.y, neuron.z)).unwrap();
+            let expected: Vec<_> = network.targets(original).into_iter().map(|to| network.neuron(to)).map(|n| (n.x, n.y, n.z)).filter(|&(x, y, _)| x <= 1 && y <= 1).collect();
+            let targets: Vec<_> = region.targets(id).into_iter().map(|to| region.neuron(to)).map(|n| (n.x, n.y, n.z)).collect();
+            assert_eq!(targets, expected, "({}, {}, {}) -> Expected connections clipped to the region", neuron.x, neuron.y, neuron.z);
+            assert!(neuron.ac.len() == targets.len() && neuron.dc.iter().all(|&(x, y, _)| x <= 1 && y <= 1), "({}, {}, {}) -> Expected no connections leaving the region", neuron.x, neuron.y, neuron.z);
+        }
+        assert_eq!(network.to_adjacency_matrix(), before, "Expected the source network to be unchanged");
+        assert_eq!(network.len(), 9, "Expected the source network to keep every neuron");
+    }
+
+    #[test]
+    fn test_k_nearest() {
+        let mut network = Network::new();
+        let far = network.add_neuron(Neuron::new(10, 10, 10, 11, 11, 11, 0, 1));
+        let near = network.add_neuron(Neuron::new(5, 5, 6, 6, 6, 7, 0, 1));
+        let tie_a = network.add_neuron(Neuron::new(5, 7, 5, 6, 8, 6, 0, 1));
+        let tie_b = network.add_neuron(Neuron::new(3, 5, 5, 4, 6, 6, 0, 1));
+        let center = network.add_neuron(Neuron::new(5, 5, 5, 6, 6, 6, 0, 1));
+
+        let nearest = network.k_nearest((5, 5, 5), 4);
+        let ids: Vec<_> = nearest.iter().map(|&(id, _)| id).collect();
+        let distances: Vec<_> = nearest.iter().map(|&(_, d)| d).collect();
+        assert_eq!(ids, vec![center, near, tie_a, tie_b], "Expected nearest neurons in ascending distance with ties broken by ID, got {:?}", nearest);
+        assert_eq!(distances, vec![0.0, 1.0, 2.0, 2.0], "Expected distances [0, 1, 2, 2], got {:?}", distances);
+        assert!(!ids.contains(&far), "Expected neuron {} to be excluded from the 4 nearest, got {:?}", far, ids);
+        assert_eq!(network.k_nearest((5, 5, 5), 10).len(), 5, "Expected k larger than the network to return every neuron");
+    }
+
+    #[test]
+    fn test_connect_k_nearest() {
+        let mut network = Network::new();
+        let a = network.add_neuron(Neuron::new(0, 0, 0, 0, 0, 1, 0, 1));
+        let b = network.add_neuron(Neuron::new(1, 0, 0, 1, 0, 1, 0, 1));
+        let c = network.add_neuron(Neuron::new(3, 0, 0, 3, 0, 1, 0, 1));
+        let d = network.add_neuron(Neuron::new(7, 0, 0, 7, 0, 1, 0, 1));
+
+        network.connect_k_nearest(2);
+
+        for id in [a, b, c, d] {
+            let neuron = network.neuron(id);
+            assert_eq!(neuron.ac.len(), 2, "({}, {}, {}) -> Expected exactly 2 axonal connections, got {}", neuron.x, neuron.y, neuron.z, neuron.ac.len());
+        }
+        assert_eq!(network.targets(a), vec![b, c], "Expected neuron {} to connect to its nearest neighbors", a);
+        assert_eq!(network.targets(b), vec![a, c], "Expected neuron {} to connect to its nearest neighbors", b);
+        assert_eq!(network.targets(c), vec![a, b], "Expected neuron {} to connect to its nearest neighbors", c);
+        assert_eq!(network.targets(d), vec![b, c], "Expected neuron {} to connect to its nearest neighbors", d);
+        assert!(!network.targets(a).contains(&d), "Expected the k-NN graph to be asymmetric: {} is not among {}'s nearest neighbors", d, a);
+    }
+
+    #[test]
+    fn test_adjacency_matrix_round_trip() {
+        let positions = [(0, 0, 0), (1, 0, 0), (0, 1, 0)];
+        let weights = vec![
+            vec![0.0, 0.5, 0.5],
+            vec![0.0, 0.0, -0.25],
+            vec![0.0, 0.0, 0.0],
+        ];
+        let nrt = [Neurotransmitter::Excitatory, Neurotransmitter::Inhibitory, Neurotransmitter::Excitatory];
+
+        let network = Network::from_adjacency_matrix(&positions, &weights, &nrt).unwrap();
+        assert_consistent(&network);
+        let exported = network.to_adjacency_matrix();
+        assert_eq!(exported, weights, "Expected the exported matrix to match the input, got {:?}", exported);
+
+        let duplicate = Network::from_adjacency_matrix(&[(0, 0, 0), (0, 0, 0)], &[vec![0.0; 2], vec![0.0; 2]], &nrt[..2]);
+        assert_eq!(duplicate.unwrap_err(), NeuronError::DuplicatePosition((0, 0, 0)), "Expected duplicate positions to be rejected");
+        let ragged = Network::from_adjacency_matrix(&positions, &weights[..2], &nrt);
+        assert_eq!(ragged.unwrap_err(), NeuronError::DimensionMismatch { expected: 3, found: 2 }, "Expected a non-square matrix to be rejected");
+        let mismatched = Network::from_adjacency_matrix(&positions[..2], &[vec![0.0, 0.5], vec![0.0, 0.0]], &nrt[1..]);
+        assert_eq!(mismatched.unwrap_err(), NeuronError::WeightSignMismatch { from: 0, to: 1 }, "Expected a positive weight from an inhibitory neuron to be rejected");
+    }
+
+    #[test]
+    fn test_strongest_path() {
+        let mut network = Network::new();
+        let source = network.add_neuron(Neuron::new(0, 0, 0, 1, 0, 0, 1, 1));
+        let strong = network.add_neuron(Neuron::new(1, 0, 0, 2, 0, 0, 0, 1));
+        let weak = network.add_neuron(Neuron::new(0, 1, 0, 1, 1, 0, 0, 1));
+        let sink = network.add_neuron(Neuron::new(1, 1, 0, 2, 1, 0, 2, 1));
+        network.neuron_mut(source).sw = 0.8;
+        network.neuron_mut(strong).sw = 0.6;
+        network.neuron_mut(weak).sw = 0.1;
+
+        network.connect(source, weak).unwrap();
+        network.connect(source, strong).unwrap();
+        network.connect(weak, sink).unwrap();
+        network.connect(strong, sink).unwrap();
+
+        let (path, strength) = network.strongest_path(source, sink).unwrap();
+        assert_eq!(path, vec![source, strong, sink], "Expected the strong route to be selected, got {:?}", path);
+        assert_eq!(strength, 0.6, "Expected the bottleneck strength to be 0.6, got {}", strength);
+        assert!(network.strongest_path(sink, source).is_none(), "Expected no path against the direction of the axonal connections");
+    }
+
+    #[test]
+    fn test_path_weight() {
+        let mut network = Network::new();
+        let a = network.add_neuron(Neuron::new(0, 0, 0, 1, 0, 0, 1, 1));
+        let b = network.add_neuron(Neuron::new(1, 0, 0, 2, 0, 0, 0, 0));
+        let c = network.add_neuron(Neuron::new(2, 0, 0, 3, 0, 0, 0, 1));
+        let d = network.add_neuron(Neuron::new(3, 0, 0, 4, 0, 0, 2, 1));
+        network.neuron_mut(a).sw = 0.5;
+        network.neuron_mut(b).sw = 0.4;
+        network.neuron_mut(c).sw = 0.25;
+        network.connect(a, b).unwrap();
+        network.connect(b, c).unwrap();
+        network.connect(c, d).unwrap();
+
+        let weight = network.path_weight(&[a, b, c, d]);
+        let expected = 0.5 * -0.4 * 0.25;
+        assert!((weight - expected).abs() < 1e-12, "Expected path weight {}, got {}", expected, weight);
+        assert_eq!(network.path_weight(&[a, c]), 0.0, "Expected a path with a missing connection to have no weight");
+    }
+
+    #[test]
+    fn test_export_ordering_is_deterministic() {
+        let positions = [(2, 0, 0), (0, 1, 0), (0, 0, 3), (1, 1, 1)];
+        let mut forward = Network::new();
+        let mut backward = Network::new();
+        for &(x, y, z) in positions.iter() {
+            forward.add_neuron(Neuron::new(x, y, z, x, y, z, 0, 1));
+        }
+        for &(x, y, z) in positions.iter().rev() {
+            backward.add_neuron(Neuron::new(x, y, z, x, y, z, 0, 1));
+        }
+        for &target in &positions[1..] {
+            forward.connect(forward.id_of(positions[0]).unwrap(), forward.id_of(target).unwrap()).unwrap();
+        }
+        for &target in positions[1..].iter().rev() {
+            backward.connect(backward.id_of(positions[0]).unwrap(), backward.id_of(target).unwrap()).unwrap();
+        }
+
+        let hub = forward.neuron(forward.id_of(positions[0]).unwrap());
+        assert_eq!(hub.axonal_connections(), vec![(0, 0, 3), (0, 1, 0), (1, 1, 1)], "Expected axonal connections sorted lexicographically");
+        assert_eq!(forward.to_dot(), backward.to_dot(), "Expected DOT output to be independent of insertion order");
+        assert_eq!(forward.to_csv(), backward.to_csv(), "Expected CSV output to be independent of insertion order");
+        let rows: Vec<_> = forward.to_csv().lines().skip(1).map(str::to_owned).collect();
+        assert_eq!(rows, vec!["2,0,0,0,0,3,1", "2,0,0,0,1,0,1", "2,0,0,1,1,1,1"], "Expected CSV edges in lexicographic order, got {:?}", rows);
+    }
+
+    #[tokio::test]
+    async fn test_plasticity_freeze() {
+        let mut network = Network::new();
+        let id = network.add_neuron(Neuron::new(0, 0, 0, 1, 1, 1, 1, 1));
+        network.neuron_mut(id).sw = 0.5;
+        network.set_plasticity_enabled(false);
+        let frozen = network.add_neuron(Neuron::new(1, 0, 0, 2, 1, 1, 1, 1));
+
+        let neuron = network.neuron_mut(id);
+        let before = neuron.plasticity_summary();
+        neuron.transmit(20.0, None).await;
+        assert_eq!(neuron.plasticity_summary(), before, "({}, {}, {}) -> Expected plasticity parameters to stay frozen", neuron.x, neuron.y, neuron.z);
+        assert!(neuron.mp > Neuron::RESTING_POTENTIAL, "({}, {}, {}) -> Expected membrane potential to respond, got {}", neuron.x, neuron.y, neuron.z, neuron.mp);
+        assert!(neuron.detect() > 0.0, "({}, {}, {}) -> Expected the neuron to still fire", neuron.x, neuron.y, neuron.z);
+        assert!(!network.neuron(frozen).plasticity_enabled, "Expected neurons added while frozen to adopt the toggle");
+
+        network.set_plasticity_enabled(true);
+        let neuron = network.neuron_mut(id);
+        neuron.reset();
+        neuron.transmit(5.0, None).await;
+        assert!(neuron.sw != 0.5, "({}, {}, {}) -> Expected synaptic weight to change once plasticity is re-enabled", neuron.x, neuron.y, neuron.z);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_jitter_positions() {
+        let mut network = Network::new();
+        for x in 0..3 {
+            for y in 0..3 {
+                network.add_neuron(Neuron::new(x * 2 + 1, y * 2 + 1, 1, x * 2 + 1, y * 2 + 1, 2, 0, 1));
+            }
+        }
+        for id in 1..network.len() {
+            network.connect(id - 1, id).unwrap();
+            network.connect(id, 0).unwrap();
+        }
+        let before: Vec<_> = network.neurons().iter().map(|n| (n.x, n.y, n.z)).collect();
+        let edges = network.to_adjacency_matrix();
+
+        let mut rng = SmallRng::seed_from_u64(17);
+        network.jitter_positions(1, &mut rng);
+
+        let after: Vec<_> = network.neurons().iter().map(|n| (n.x, n.y, n.z)).collect();
+        let moved = before.iter().zip(&after).filter(|(b, a)| b != a).count();
+        assert!(moved > 0, "Expected some neurons to move, got {:?}", after);
+        let distinct: std::collections::HashSet<_> = after.iter().collect();
+        assert_eq!(distinct.len(), after.len(), "Expected no two neurons to share a coordinate, got {:?}", after);
+        assert_consistent(&network);
+        assert_eq!(network.to_adjacency_matrix(), edges, "Expected connectivity to be preserved across the jitter");
+    }
+
+    #[test]
+    fn test_degree_and_isolated() {
+        let mut network = Network::new();
+        let hub = network.add_neuron(Neuron::new(0, 0, 0, 1, 0, 0, 1, 1));
+        let a = network.add_neuron(Neuron::new(1, 0, 0, 2, 0, 0, 0, 1));
+        let b = network.add_neuron(Neuron::new(0, 1, 0, 1, 1, 0, 0, 1));
+        let lonely = network.add_neuron(Neuron::new(5, 5, 5, 6, 5, 5, 0, 1));
+        network.connect(hub, a).unwrap();
+        network.connect(hub, b).unwrap();
+        network.connect(a, hub).unwrap();
+
+        assert_eq!(network.degree(hub), (2, 1), "Expected hub degree (2, 1), got {:?}", network.degree(hub));
+        assert_eq!(network.degree(a), (1, 1), "Expected neuron {} degree (1, 1), got {:?}", a, network.degree(a));
+        assert_eq!(network.degree(b), (0, 1), "Expected neuron {} degree (0, 1), got {:?}", b, network.degree(b));
+        assert_eq!(network.isolated(), vec![lonely], "Expected only neuron {} to be isolated, got {:?}", lonely, network.isolated());
+    }
+
+    #[test]
+    fn test_to_graphml() {
+        let mut network = Network::new();
+        let a = network.add_neuron(Neuron::new(0, 0, 0, 1, 0, 0, 1, 0));
+        let b = network.add_neuron(Neuron::new(1, 0, 0, 2, 0, 0, 2, 1));
+        network.neuron_mut(a).sw = 0.75;
+        network.connect(a, b).unwrap();
+        network.neuron_mut(a).ac.insert((9, 9, 9)); // Stale connection to a missing neuron
+
+        let graphml = network.to_graphml();
+        assert!(graphml.starts_with("<?xml") && graphml.contains("<graphml") && graphml.trim_end().ends_with("</graphml>"), "Expected a GraphML document, got {}", graphml);
+        assert_eq!(graphml.matches("<node id=").count(), 2, "Expected two node elements, got {}", graphml);
+        assert_eq!(graphml.matches("<edge ").count(), 1, "Expected the stale edge to be dropped, got {}", graphml);
+        assert!(graphml.contains("<edge source=\"n0\" target=\"n1\">"), "Expected an edge from n0 to n1, got {}", graphml);
+        assert!(graphml.contains("<data key=\"weight\">0.75</data>") && graphml.contains("<data key=\"sign\">-1</data>"), "Expected the inhibitory edge attributes, got {}", graphml);
+        assert!(graphml.contains("<data key=\"nt\">2</data>"), "Expected the motor neuron type attribute, got {}", graphml);
+    }
+
+    #[test]
+    fn test_inject_current() {
+        let mut network = Network::new();
+        let driven = network.add_neuron(Neuron::new(0, 0, 0, 1, 0, 0, 1, 1));
+        let idle = network.add_neuron(Neuron::new(1, 0, 0, 2, 0, 0, 1, 1));
+
+        network.inject_current(5.0, Some(&[driven]));
+        let first = network.neuron(driven).ap;
+        network.inject_current(5.0, Some(&[driven]));
+        let neuron = network.neuron(driven);
+        assert!(first > 0.0 && neuron.ap > first, "({}, {}, {}) -> Expected accumulated potential to build up, got {} then {}", neuron.x, neuron.y, neuron.z, first, neuron.ap);
+        assert!(neuron.mp < neuron.tp, "({}, {}, {}) -> Expected the current to stay sub-threshold, got mp {}, tp {}", neuron.x, neuron.y, neuron.z, neuron.mp, neuron.tp);
+        assert_eq!(network.neuron(idle).ap, 0.0, "Expected untargeted neuron {} to be left alone", idle);
+
+        network.inject_current(5.0, None);
+        assert_eq!(network.neuron(idle).ap, first, "Expected a None target list to drive every neuron");
+    }
+
+    #[test]
+    fn test_edge_weight() {
+        let mut network = Network::new();
+        let inhibitory = network.add_neuron(Neuron::new(0, 0, 0, 1, 0, 0, 0, 0));
+        let target = network.add_neuron(Neuron::new(1, 0, 0, 2, 0, 0, 2, 1));
+        network.neuron_mut(inhibitory).sw = 0.4;
+        network.connect(inhibitory, target).unwrap();
+
+        assert_eq!(network.edge_weight(inhibitory, target), Some(-0.4), "Expected the inhibitory edge weight to be negative");
+        assert_eq!(network.edge_weight(target, inhibitory), None, "Expected no weight for a missing connection");
+    }
+
+    #[test]
+    fn test_pruning_interval() {
+        let mut network = Network::new();
+        let weak = network.add_neuron(Neuron::new(0, 0, 0, 1, 0, 0, 0, 1));
+        let strong = network.add_neuron(Neuron::new(1, 0, 0, 2, 0, 0, 0, 1));
+        let target = network.add_neuron(Neuron::new(2, 0, 0, 3, 0, 0, 0, 1));
+        network.neuron_mut(weak).sw = 0.05;
+        network.neuron_mut(weak).sst = 0.05;
+        network.neuron_mut(target).sw = 0.1;
+        network.connect(weak, target).unwrap();
+        network.connect(strong, target).unwrap();
+        network.set_pruning_interval(3);
+
+        for tick in 1..=2 {
+            network.tick();
+            assert!(network.edge_weight(weak, target).is_some(), "Expected the weak connection to survive tick {}", tick);
+        }
+        network.tick();
+        assert_eq!(network.clock(), 3, "Expected the clock to count ticks");
+        assert!(network.edge_weight(weak, target).is_none(), "Expected the weak connection to be pruned at tick 3");
+        assert!(network.edge_weight(strong, target).is_some(), "Expected the strong connection to survive pruning");
+        assert!(!network.neuron(target).dc.contains(&(0, 0, 0)), "Expected the dendritic back-reference to be pruned too");
+    }
+
+    #[test]
+    fn test_tick_propagates_signals() {
+        let mut network = Network::new();
+        let source = network.add_neuron(Neuron::new(0, 0, 0, 1, 0, 0, 1, 1));
+        let target = network.add_neuron(Neuron::new(1, 0, 0, 2, 0, 0, 2, 1));
+        network.connect(source, target).unwrap();
+        network.inject_current(20.0, Some(&[source]));
+
+        let fired = network.tick();
+        assert_eq!(fired.len(), 1, "Expected only the driven neuron to fire, got {:?}", fired);
+        assert_eq!(fired[0].0, source, "Expected neuron {} to fire, got {:?}", source, fired);
+        assert!(network.neuron(target).ap > 0.0, "Expected the signal to reach neuron {}", target);
+    }
+
+    #[test]
+    fn test_tick_relaxes_silent_threshold() {
+        let mut network = Network::new();
+        let id = network.add_neuron(Neuron::new(0, 0, 0, 1, 0, 0, 1, 1));
+        network.neuron_mut(id).params.cumulative_threshold_adaptation = true;
+        let baseline = network.neuron(id).tp;
+        for _ in 0..3 {
+            network.neuron_mut(id).arp = 0.0;
+            network.inject_current(20.0, Some(&[id]));
+            network.tick();
+        }
+        let (adapted_tp, adapted_ta) = (network.neuron(id).tp, network.neuron(id).ta);
+        assert!(adapted_ta > 0.0 && adapted_tp > baseline, "Expected firing to raise the threshold above {}, got {}", baseline, adapted_tp);
+
+        for _ in 0..500 {
+            network.tick();
+        }
+        let neuron = network.neuron(id);
+        assert!(neuron.ta < adapted_ta * 0.01, "Expected the adaptation of a silent neuron to decay, got {} -> {}", adapted_ta, neuron.ta);
+        assert!(neuron.tp < adapted_tp && neuron.tp - baseline < 0.01 * (adapted_tp - baseline) + 1e-9, "Expected the threshold to return toward {}, got {} -> {}", baseline, adapted_tp, neuron.tp);
+    }
+
+    #[test]
+    fn test_detect_hubs() {
+        let mut network = Network::new();
+        let leaves: Vec<_> = (0..4).map(|x| network.add_neuron(Neuron::new(x, 0, 0, x, 0, 1, 0, 1))).collect();
+        let center = network.add_neuron(Neuron::new(2, 2, 0, 2, 2, 1, 0, 1));
+        for &leaf in &leaves {
+            network.connect(center, leaf).unwrap();
+        }
+        network.connect(leaves[0], center).unwrap();
+
+        let hubs = network.detect_hubs(2);
+        assert_eq!(hubs.len(), 2, "Expected the top 2 hubs, got {:?}", hubs);
+        assert_eq!(hubs[0], (center, 6.0), "Expected the star center to rank first, got {:?}", hubs);
+        assert_eq!(hubs[1].0, leaves[0], "Expected the reciprocally connected leaf to rank second, got {:?}", hubs);
+    }
+
+    #[test]
+    fn test_spike_raster() {
+        let mut network = Network::new();
+        let source = network.add_neuron(Neuron::new(0, 0, 0, 1, 0, 0, 1, 1));
+        let target = network.add_neuron(Neuron::new(1, 0, 0, 2, 0, 0, 2, 1));
+        network.connect(source, target).unwrap();
+        network.set_recording(true);
+
+        for driven in [vec![source], vec![target], vec![source, target]] {
+            network.inject_current(20.0, Some(&driven));
+            network.tick();
+        }
+        let expected = [(source, 0), (source, 1), (target, 1), (source, 2), (target, 2)];
+        assert_eq!(network.spike_raster().spikes(), &expected[..], "Expected the raster to hold every spike in tick order");
+        assert!(network.spike_raster().to_csv().starts_with("neuron_id,tick\n0,0\n0,1\n1,1\n"), "Expected a neuron_id,tick CSV, got {}", network.spike_raster().to_csv());
+
+        network.set_recording(false);
+        network.inject_current(20.0, None);
+        network.tick();
+        assert_eq!(network.spike_raster().len(), expected.len(), "Expected no spikes to be recorded while recording is off");
+    }
+
+    #[test]
+    fn test_topological_order() {
+        let mut network = Network::new();
+        let ids: Vec<_> = (0..5).map(|x| network.add_neuron(Neuron::new(x, 0, 0, x, 0, 1, 0, 1))).collect();
+        for (from, to) in [(3, 1), (3, 2), (1, 0), (2, 0), (0, 4)] {
+            network.connect(ids[from], ids[to]).unwrap();
+        }
+
+        let order = network.topological_order().unwrap();
+        assert_eq!(order.len(), ids.len(), "Expected every neuron in the order, got {:?}", order);
+        let rank = |id| order.iter().position(|&n| n == id).unwrap();
+        for id in 0..ids.len() {
+            for target in network.targets(id) {
+                assert!(rank(id) < rank(target), "Expected neuron {} before neuron {}, got {:?}", id, target, order);
+            }
+        }
+
+        network.connect(ids[0], ids[3]).unwrap();
+        let error = network.topological_order().unwrap_err();
+        assert!([ids[0], ids[1], ids[2], ids[3]].contains(&error.node), "Expected the error to name a neuron on the cycle, got {:?}", error);
+    }
+
+    #[test]
+    fn test_evaluate_feedforward() {
+        let mut network = Network::new();
+        let inputs = [network.add_neuron(Neuron::new(0, 0, 0, 0, 0, 1, 1, 1)), network.add_neuron(Neuron::new(0, 1, 0, 0, 1, 1, 1, 1))];
+        let output = network.add_neuron(Neuron::new(1, 0, 0, 1, 0, 1, 2, 1));
+        for &input in &inputs {
+            network.connect(input, output).unwrap();
+        }
+
+        // Hand propagation on standalone copies of the neurons
+        let signals: Vec<f64> = inputs.iter().zip([30.0, 25.0]).map(|(&id, input)| {
+            let mut neuron = network.neuron(id).clone();
+            neuron.transmit_sync(input);
+            neuron.detect()
+        }).collect();
+        let mut expected = network.neuron(output).clone();
+        expected.transmit_sync(signals.iter().sum());
+        let expected = expected.detect();
+        assert!(expected > 0.0, "Expected the hand propagation to fire the output neuron, got {:?}", signals);
+
+        let result = network.evaluate_feedforward(&HashMap::from([(inputs[0], 30.0), (inputs[1], 25.0)])).unwrap();
+        assert_eq!(result[&inputs[0]], signals[0], "Expected the first input neuron output to match");
+        assert_eq!(result[&inputs[1]], signals[1], "Expected the second input neuron output to match");
+        assert_eq!(result[&output], expected, "Expected the output neuron value to match the hand propagation");
+
+        network.connect(output, inputs[0]).unwrap();
+        assert!(network.evaluate_feedforward(&HashMap::new()).is_err(), "Expected a cyclic network to be rejected");
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_connect_distance_weighted() {
+        let mut network = Network::new();
+        let source = network.add_neuron(Neuron::new(0, 0, 0, 0, 0, 1, 0, 1));
+        let near = network.add_neuron(Neuron::new(1, 0, 0, 1, 0, 1, 0, 1));
+        let far = network.add_neuron(Neuron::new(20, 0, 0, 20, 0, 1, 0, 1));
+        network.add_neuron(Neuron::new(0, 5, 0, 0, 5, 1, 0, 1));
+
+        let mut wired = network.clone();
+        wired.connect_distance_weighted(source, 2, &mut SmallRng::seed_from_u64(3));
+        assert_eq!(wired.degree(source).0, 2, "Expected the source to gain 2 connections, got {:?}", wired.targets(source));
+        let mut again = network.clone();
+        again.connect_distance_weighted(source, 2, &mut SmallRng::seed_from_u64(3));
+        assert_eq!(again.targets(source), wired.targets(source), "Expected the same targets under the same seed");
+        assert_consistent(&wired);
+
+        let (mut near_count, mut far_count) = (0, 0);
+        for seed in 0..200 {
+            let mut wired = network.clone();
+            wired.connect_distance_weighted(source, 1, &mut SmallRng::seed_from_u64(seed));
+            let target = wired.targets(source)[0];
+            near_count += (target == near) as usize;
+            far_count += (target == far) as usize;
+        }
+        assert!(near_count > far_count, "Expected the closer neuron to be chosen more often, got {} near vs {} far", near_count, far_count);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_connect_distance_weighted_co_located() {
+        let mut network = Network::new();
+        let source = network.add_neuron(Neuron::new(3, 3, 3, 3, 3, 4, 0, 1));
+        let co_located = network.add_neuron(Neuron::new(4, 3, 3, 4, 3, 4, 0, 1));
+        network.add_neuron(Neuron::new(9, 3, 3, 9, 3, 4, 0, 1));
+
+        // Move a neuron onto the source, bypassing the occupancy guard of `add_neuron`
+        network.neuron_mut(co_located).x = 3;
+        network.connect_distance_weighted(source, 2, &mut SmallRng::seed_from_u64(5));
+        assert_eq!(network.degree(source).0, 2, "Expected both candidates, including the co-located one, to be connected, got {:?}", network.targets(source));
+    }
+
+    #[test]
+    fn test_merge_duplicate_positions() {
+        let mut network = Network::new();
+        let ids: Vec<_> = (0..4).map(|x| network.add_neuron(Neuron::new(x, 0, 0, x, 0, 1, 0, 1))).collect();
+        network.connect(ids[0], ids[1]).unwrap();
+        network.connect(ids[2], ids[3]).unwrap();
+        network.connect(ids[1], ids[2]).unwrap();
+        network.neuron_mut(ids[1]).sw = 0.2;
+        network.neuron_mut(ids[2]).sw = 0.6;
+        for (id, ta, fire_count) in [(ids[1], 1.0, 3), (ids[2], 2.0, 4)] {
+            network.neuron_mut(id).ta = ta;
+            network.neuron_mut(id).fire_count = fire_count;
+        }
+
+        // Move neuron 2 onto neuron 1, bypassing the occupancy guard of `add_neuron`
+        network.neuron_mut(ids[2]).x = 1;
+        for id in [ids[1], ids[3]] {
+            let neuron = network.neuron_mut(id);
+            for set in [&mut neuron.ac, &mut neuron.dc] {
+                if set.remove(&(2, 0, 0)) {
+                    set.insert((1, 0, 0));
+                }
+            }
+        }
+
+        assert_eq!(network.merge_duplicate_positions(), 1, "Expected one merge");
+        assert_eq!(network.len(), 3, "Expected the duplicate to be removed");
+        let merged = network.id_of((1, 0, 0)).unwrap();
+        assert_eq!(network.neuron(merged).axonal_connections(), vec![(3, 0, 0)], "Expected the union of axonal connections without the self-connection");
+        assert_eq!(network.neuron(merged).dendritic_connections(), vec![(0, 0, 0)], "Expected the union of dendritic connections without the self-connection");
+        assert!((network.neuron(merged).sw - 0.4).abs() < 1e-12, "Expected the synaptic weights to be averaged, got {}", network.neuron(merged).sw);
+        assert_eq!(network.neuron(merged).ta, 1.5, "Expected the threshold adaptations to be averaged");
+        assert_eq!(network.neuron(merged).fire_count, 7, "Expected the firing counts to be summed");
+        assert_eq!(network.id_of((3, 0, 0)), Some(2), "Expected later identifiers to shift down");
+        assert_consistent(&network);
+    }
+
+    #[test]
+    fn test_merge_duplicate_positions_renumbers_state() {
+        let mut network = Network::new();
+        let ids: Vec<_> = (0..5).map(|x| network.add_neuron(Neuron::new(x, 0, 0, x, 0, 1, 0, 1))).collect();
+        network.set_recording(true);
+        network.inject_current(20.0, Some(&[ids[4]]));
+        network.tick();
+        assert_eq!(network.spike_raster().spikes(), &[(ids[4], 0)], "Expected the driven neuron to fire");
+
+        network.inject_spike_train(ids[3], &[(5, 20.0)]);
+        network.inject_spike_train(ids[2], &[(5, 20.0)]);
+        // Move neuron 2 onto neuron 1, bypassing the occupancy guard of `add_neuron`
+        network.neuron_mut(ids[2]).x = 1;
+        assert_eq!(network.merge_duplicate_positions(), 1, "Expected one merge");
+
+        let (last, fired) = (network.id_of((3, 0, 0)).unwrap(), network.id_of((4, 0, 0)).unwrap());
+        let merged = network.id_of((1, 0, 0)).unwrap();
+        assert_eq!(network.spike_raster().spikes(), &[(fired, 0)], "Expected recorded spikes to follow the renumbered neuron");
+        while network.clock() <= 5 {
+            network.tick();
+        }
+        assert!(network.neuron(last).ap > 0.0, "Expected the queued input to reach the renumbered neuron {}", last);
+        assert!(network.neuron(merged).ap > 0.0, "Expected the input queued for the removed neuron to reach the neuron it merged into");
+    }
+
+    #[test]
+    fn test_metrics() {
+        let mut network = Network::new();
+        let source = network.add_neuron(Neuron::new(0, 0, 0, 1, 0, 0, 1, 1));
+        let targets: Vec<_> = (1..3).map(|x| network.add_neuron(Neuron::new(x, 0, 0, x, 0, 1, 2, 1))).collect();
+        for &target in &targets {
+            network.connect(source, target).unwrap();
+        }
+
+        let mut fires = 0;
+        let mut transmits = 0;
+        for _ in 0..5 {
+            network.inject_current(20.0, Some(&[source]));
+            let fired = network.tick();
+            fires += fired.len() as u64;
+            transmits += fired.iter().map(|&(id, _)| network.targets(id).len() as u64).sum::<u64>();
+        }
+
+        let metrics = network.metrics();
+        assert_eq!(metrics.ticks, 5, "Expected 5 ticks, got {:?}", metrics);
+        assert!(fires > 0, "Expected the driven neuron to fire");
+        assert_eq!(metrics.fires, fires, "Expected the fire count to match the ticks, got {:?}", metrics);
+        assert_eq!(metrics.transmits, transmits, "Expected one transmit per target of each fire, got {:?}", metrics);
+        assert!(metrics.blocked <= metrics.transmits, "Expected blocked signals to be a subset of transmits, got {:?}", metrics);
+
+        network.reset_metrics();
+        assert_eq!(network.metrics().ticks, 0, "Expected the counters to be cleared");
+    }
+
+    #[test]
+    fn test_prune_inactive() {
+        let mut network = Network::new();
+        let active = network.add_neuron(Neuron::new(0, 0, 0, 1, 0, 0, 1, 1));
+        let silent = network.add_neuron(Neuron::new(0, 2, 0, 0, 2, 1, 1, 1));
+        let target = network.add_neuron(Neuron::new(1, 0, 0, 2, 0, 0, 2, 1));
+        network.connect(active, target).unwrap();
+        network.connect(silent, target).unwrap();
+        network.connect(silent, active).unwrap();
+
+        for _ in 0..3 {
+            network.inject_current(20.0, Some(&[active]));
+            network.tick();
+        }
+        assert!(network.neuron(active).fire_count > 0, "Expected the driven neuron to have fired");
+        assert_eq!(network.neuron(silent).fire_count, 0, "Expected the undriven neuron never to fire");
+
+        assert_eq!(network.prune_inactive(1), 2, "Expected both outgoing edges of the silent neuron to be pruned");
+        assert_eq!(network.targets(active), vec![target], "Expected the active neuron to keep its connection");
+        assert!(network.targets(silent).is_empty(), "Expected the silent neuron to lose its connections");
+        assert_consistent(&network);
+    }
+
+    #[test]
+    fn test_prune_weakest_fraction() {
+        let mut network = Network::new();
+        let target = network.add_neuron(Neuron::new(5, 5, 5, 6, 5, 5, 2, 1));
+        let weights = [0.8, -0.1, 0.5, 0.2];
+        let sources: Vec<_> = weights
+            .iter()
+            .enumerate()
+            .map(|(i, &sw)| {
+                let id = network.add_neuron(Neuron::new(i, 0, 0, i, 1, 0, 1, 1));
+                network.neuron_mut(id).sw = sw;
+                network.connect(id, target).unwrap();
+                id
+            })
+            .collect();
+
+        assert_eq!(network.prune_weakest_fraction(0.5), 2, "Expected half of the four edges to be removed");
+        for (source, sw) in sources.iter().zip(weights) {
+            let kept = network.edge_weight(*source, target).is_some();
+            assert_eq!(kept, sw.abs() >= 0.5, "Expected the edge with weight {} to be {}", sw, if sw.abs() >= 0.5 { "kept" } else { "pruned" });
+        }
+        assert_consistent(&network);
+
+        assert_eq!(network.prune_weakest_fraction(0.0), 0, "Expected a zero fraction to remove nothing");
+        assert_eq!(network.prune_weakest_fraction(2.0), 2, "Expected a fraction above one to remove every remaining edge");
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_from_layers() {
+        let layers = [
+            LayerSpec { count: 3, nt: 1, nrt: Neurotransmitter::Excitatory, connection_probability: 1.0 },
+            LayerSpec { count: 6, nt: 0, nrt: Neurotransmitter::Excitatory, connection_probability: 0.5 },
+            LayerSpec { count: 4, nt: 0, nrt: Neurotransmitter::Inhibitory, connection_probability: 1.0 },
+        ];
+        let network = Network::from_layers(&layers, &mut SmallRng::seed_from_u64(11));
+        assert_consistent(&network);
+        assert_eq!(network.len(), 13, "Expected one neuron per layer slot");
+
+        let kinds: Vec<(usize, u32, u32)> = network.neurons().iter().map(|neuron| (neuron.x, neuron.nt, neuron.nrt)).collect();
+        let expected: Vec<(usize, u32, u32)> = [(0, 1, 1, 3), (1, 0, 1, 6), (2, 0, 0, 4)].iter()
+            .flat_map(|&(x, nt, nrt, count)| std::iter::repeat_n((x, nt, nrt), count))
+            .collect();
+        assert_eq!(kinds, expected, "Expected each layer's neuron type and neurotransmitter, got {:?}", kinds);
+
+        let layer = |id: usize| network.neuron(id).x;
+        let edges: Vec<(usize, usize)> = (0..network.len()).flat_map(|from| network.targets(from).into_iter().map(move |to| (from, to))).collect();
+        assert!(edges.iter().all(|&(from, to)| layer(to) == layer(from) + 1), "Expected connections only to the next layer, got {:?}", edges);
+        let dense = edges.iter().filter(|&&(from, _)| layer(from) == 0).count();
+        let sparse = edges.iter().filter(|&&(from, _)| layer(from) == 1).count();
+        assert_eq!(dense, 18, "Expected the input layer to connect fully to the hidden layer");
+        assert!(sparse > 0 && sparse < 24, "Expected partial hidden-to-interneuron connectivity, got {} of 24", sparse);
+
+        let replay = Network::from_layers(&layers, &mut SmallRng::seed_from_u64(11));
+        assert_eq!(replay.to_adjacency_matrix(), network.to_adjacency_matrix(), "Expected the same seed to reproduce the connectivity");
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_rewire_random() {
+        let ring = || {
+            let mut network = Network::new();
+            let n = 100;
+            for x in 0..n {
+                network.add_neuron(Neuron::new(x, 0, 0, x, 0, 1, 0, 1));
+            }
+            for from in 0..n {
+                network.connect(from, (from + 1) % n).unwrap();
+                network.connect(from, (from + 2) % n).unwrap();
+            }
+            network
+        };
+        let edge_count = |network: &Network| network.neurons().iter().map(|neuron| neuron.ac.len()).sum::<usize>();
+
+        let mut network = ring();
+        let rewired = network.rewire_random(0.3, &mut SmallRng::seed_from_u64(21));
+        let fraction = rewired as f64 / 200.0;
+        assert!((0.2..=0.4).contains(&fraction), "Expected about 30% of the connections to be rewired, got {}", fraction);
+        assert_consistent(&network);
+        assert_eq!(edge_count(&network), 200, "Expected the number of connections to be preserved");
+        assert!(network.neurons().iter().all(|neuron| !neuron.ac.contains(&(neuron.x, neuron.y, neuron.z))), "Expected no self-loops");
+
+        let mut replay = ring();
+        assert_eq!(replay.rewire_random(0.3, &mut SmallRng::seed_from_u64(21)), rewired, "Expected the same seed to rewire the same number of connections");
+        assert_eq!(replay.to_adjacency_matrix(), network.to_adjacency_matrix(), "Expected the same seed to reproduce the rewiring");
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_rewire_random_over_fan_out() {
+        let mut network = Network::new();
+        let ids: Vec<_> = (0..6).map(|x| network.add_neuron(Neuron::new(x, 0, 0, x, 0, 1, 0, 1))).collect();
+        for &target in &ids[1..4] {
+            network.connect(ids[0], target).unwrap();
+        }
+        network.neuron_mut(ids[0]).params.max_fan_out = Some(2);
+        let before = network.to_adjacency_matrix();
+
+        let rewired = network.rewire_random(1.0, &mut SmallRng::seed_from_u64(4));
+        assert_eq!(rewired, 0, "Expected no rewiring for a neuron over its fan-out limit");
+        assert_eq!(network.to_adjacency_matrix(), before, "Expected the original connections to be kept");
+        assert_consistent(&network);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_evolve() {
+        let mut network = Network::new();
+        let mut rng = SmallRng::seed_from_u64(5);
+        for x in 0..20 {
+            let id = network.add_neuron(Neuron::new(x, 0, 0, x, 1, 0, 1, 1));
+            network.neuron_mut(id).sw = 0.05 * (x % 7) as f64;
+        }
+        for id in 1..network.len() {
+            network.connect(id - 1, id).unwrap();
+        }
+        let before = network.to_sparse_coo().1.len();
+
+        let mean_fitness = |network: &Network| network.neurons().iter().map(|neuron| neuron.sw).sum::<f64>() / network.len() as f64;
+        let initial = mean_fitness(&network);
+        let mut history = vec![initial];
+        for _ in 0..10 {
+            network.evolve(|neuron| neuron.sw, 0.2, &mut rng);
+            history.push(mean_fitness(&network));
+        }
+        assert!(history.last().unwrap() > &(initial + 0.1), "Expected the mean fitness to increase over generations, got {:?}", history);
+        assert!(network.neurons().iter().all(|neuron| neuron.validate().is_ok()), "Expected every evolved neuron to stay valid");
+        assert_eq!(network.to_sparse_coo().1.len(), before, "Expected connections to be preserved");
+        assert_consistent(&network);
+    }
+
+    #[test]
+    fn test_propagate_bounded() {
+        let mut network = Network::new();
+        let ring: Vec<_> = (0..4).map(|x| network.add_neuron(Neuron::new(x, 0, 0, x, 1, 0, 0, 1))).collect();
+        for i in 0..ring.len() {
+            network.connect(ring[i], ring[(i + 1) % ring.len()]).unwrap();
+        }
+        network.set_global_modulation(Neuron::MAX_NEUROTRANSMITTER_CONCENTRATION);
+
+        let mut unbounded = network.clone();
+        let budget = 50.0;
+        let activations = network.propagate_bounded(ring[0], 30.0, budget);
+        assert!(ring.iter().all(|id| activations.iter().any(|(fired, _)| fired == id)), "Expected the wave to travel around the ring, got {:?}", activations);
+        assert!(activations.len() > ring.len(), "Expected the ring to re-excite itself, got {:?}", activations);
+
+        // Each firing in a ring delivers exactly one signal; only the last one went undelivered
+        let energy: Vec<f64> = activations.iter().map(|(_, output)| output.abs()).collect();
+        let delivered: f64 = energy[..energy.len() - 1].iter().sum();
+        assert!(delivered <= budget, "Expected at most {} to be delivered, got {}", budget, delivered);
+        assert!(delivered + energy[energy.len() - 1] > budget, "Expected propagation to halt only once the budget ran out, got {:?}", activations);
+
+        let larger = unbounded.propagate_bounded(ring[0], 30.0, 10.0 * budget);
+        assert!(larger.len() > activations.len(), "Expected a larger budget to sustain more firings, got {} against {}", larger.len(), activations.len());
+    }
+
+    #[test]
+    fn test_firing_rate_histogram() {
+        let mut network = Network::new();
+        for (x, fr) in [0.0, 0.05, 0.3, 0.45, 0.5, 0.99, 1.0].into_iter().enumerate() {
+            let id = network.add_neuron(Neuron::new(x, 0, 0, x, 1, 0, 0, 1));
+            network.neuron_mut(id).fr = fr;
+        }
+
+        let histogram = network.firing_rate_histogram(4);
+        assert_eq!(histogram, vec![2, 2, 1, 2], "Expected the rates bucketed into quarters, got {:?}", histogram);
+        assert_eq!(histogram.iter().sum::<usize>(), network.len(), "Expected every neuron to be counted once");
+        assert!(network.firing_rate_histogram(0).is_empty(), "Expected no bins to give an empty histogram");
+
+        let mean = network.mean_firing_rate();
+        assert!((mean - 3.29 / 7.0).abs() < 1e-12, "Expected the mean firing rate, got {}", mean);
+        assert_eq!(Network::new().mean_firing_rate(), 0.0, "Expected an empty network to have a zero mean rate");
+    }
+
+    #[test]
+    fn test_isi_statistics() {
+        let regular = isi_statistics(&[0, 10, 20, 30, 40]);
+        assert_eq!((regular.count, regular.mean, regular.min, regular.max), (4, 10.0, 10, 10), "Expected four intervals of 10 ticks, got {:?}", regular);
+        assert_eq!(regular.cv, 0.0, "Expected a clock-like train to have zero CV, got {:?}", regular);
+
+        let irregular = isi_statistics(&[40, 0, 1, 25, 3, 50]);
+        assert_eq!((irregular.min, irregular.max), (1, 22), "Expected the extreme intervals of the sorted train, got {:?}", irregular);
+        assert!(irregular.cv > 0.5, "Expected an irregular train to have a high CV, got {:?}", irregular);
+
+        assert_eq!(isi_statistics(&[7]).count, 0, "Expected no intervals from a single spike");
+    }
+
+    #[test]
+    fn test_spike_cross_correlation() {
+        let a = [1, 4, 9, 12, 20, 27];
+        let b: Vec<u64> = a.iter().map(|tick| tick + 3).chain([2, 15]).collect();
+
+        let correlation = spike_cross_correlation(&a, &b, 5);
+        assert_eq!(correlation.len(), 11, "Expected one value per lag, got {:?}", correlation);
+        assert_eq!(correlation.first().unwrap().0, -5, "Expected lags to start at -max_lag");
+        let peak = correlation.iter().max_by(|x, y| x.1.total_cmp(&y.1)).unwrap();
+        assert_eq!(peak.0, 3, "Expected the correlation to peak at lag 3, got {:?}", correlation);
+
+        let reversed = spike_cross_correlation(&b, &a, 5);
+        let peak = reversed.iter().max_by(|x, y| x.1.total_cmp(&y.1)).unwrap();
+        assert_eq!(peak.0, -3, "Expected the reversed correlation to peak at lag -3, got {:?}", reversed);
+        assert!(spike_cross_correlation(&a, &[], 2).iter().all(|&(_, c)| c == 0.0), "Expected no correlation with an empty train");
+    }
+
+    #[test]
+    fn test_fire_wave() {
+        let mut network = Network::new();
+        let chain: Vec<_> = [0, 2, 5, 9].iter().map(|&x| network.add_neuron(Neuron::new(x, 0, 0, x, 0, 1, 0, 1))).collect();
+        for pair in chain.windows(2) {
+            network.connect(pair[0], pair[1]).unwrap();
+        }
+        for &id in &chain[1..] {
+            network.neuron_mut(id).nc = 3.0;
+        }
+
+        let wave = network.fire_wave(chain[0], 30.0);
+        let ids: Vec<_> = wave.iter().map(|&(id, _)| id).collect();
+        assert_eq!(ids, chain, "Expected every neuron in the chain to fire in order, got {:?}", wave);
+        for (pair, gap) in wave.windows(2).zip([2, 3, 4]) {
+            assert_eq!(pair[1].1 - pair[0].1, gap, "Expected the firing ticks to follow the inter-neuron distances, got {:?}", wave);
+        }
+        assert_eq!(network.clock(), 0, "Expected the wave not to advance the clock");
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_apply_dropout() {
+        let mut network = Network::new();
+        for x in 0..200 {
+            network.add_neuron(Neuron::new(x, 0, 0, x, 0, 1, 0, 1));
+        }
+        for id in 1..network.len() {
+            network.connect(id - 1, id).unwrap();
+        }
+        network.inject_current(20.0, None);
+
+        let dropped = network.clone().apply_dropout(0.25, &mut SmallRng::seed_from_u64(11));
+        assert_eq!(network.apply_dropout(0.25, &mut SmallRng::seed_from_u64(11)), dropped, "Expected the same selection under the same seed");
+        let fraction = dropped.len() as f64 / network.len() as f64;
+        assert!((0.15..=0.35).contains(&fraction), "Expected roughly a quarter of the neurons to be dropped, got {}", fraction);
+
+        let before: Vec<_> = network.neurons().iter().map(|n| (n.ap, n.mp, n.fr, n.fire_count)).collect();
+        let fired = network.tick();
+        for &id in &dropped {
+            let n = network.neuron(id);
+            assert_eq!((n.ap, n.mp, n.fr, n.fire_count), before[id], "Expected dropped neuron {} not to change during the tick", id);
+        }
+        assert_eq!(fired.len(), network.len() - dropped.len(), "Expected every other neuron to fire");
+        assert!(fired.iter().all(|(id, _)| !dropped.contains(id)), "Expected no dropped neuron to fire");
+    }
+
+    #[test]
+    fn test_to_sparse_coo() {
+        let mut network = Network::new();
+        let ids: Vec<_> = (0..4).map(|x| network.add_neuron(Neuron::new(x, 0, 0, x, 0, 1, 0, u32::from(x != 2)))).collect();
+        network.neuron_mut(ids[2]).sw = 0.5;
+        for (from, to) in [(0, 3), (2, 1), (0, 1)] {
+            network.connect(ids[from], ids[to]).unwrap();
+        }
+
+        let (order, triples) = network.to_sparse_coo();
+        assert_eq!(order, ids, "Expected rows and columns in identifier order");
+        assert_eq!(triples, vec![(0, 1, 1.0), (0, 3, 1.0), (2, 1, -0.5)], "Expected one signed triple per connection");
+        let dense = network.to_adjacency_matrix();
+        let nonzero = dense.iter().flatten().filter(|&&w| w != 0.0).count();
+        assert_eq!(nonzero, triples.len(), "Expected absent edges to produce no entries");
+    }
+}
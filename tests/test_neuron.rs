@@ -1,5 +1,21 @@
 // tests/test_neuron.rs
-use neuron::Neuron;
+#![cfg(feature = "std")]
+// The baseline transmit calls below pass `Some(&mut ..)`; keep them verbatim.
+#![allow(clippy::unnecessary_mut_passed)]
+use neuron::{DistanceAttenuation, FiringState, Neuron, PlasticityRule, TransmitError};
+use std::time::Duration;
+#[cfg(feature = "rand")]
+use rand::{rngs::SmallRng, SeedableRng};
+
+#[derive(Debug)]
+struct MultiplicativeRule;
+
+impl PlasticityRule for MultiplicativeRule {
+    fn apply(&self, neuron: &mut Neuron, _input: f64) {
+        neuron.sw *= 1.0 + (neuron.ltp + neuron.ltd) * neuron.pr;
+        neuron.sw = neuron.sw.clamp(Neuron::MIN_LTD, Neuron::MAX_LTP);
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -15,12 +31,25 @@ mod tests {
         assert_eq!(neuron.tp, Neuron::MIN_THRESHOLD_POTENTIAL, "Expected threshold potential to be {}, got {}", Neuron::MIN_THRESHOLD_POTENTIAL, neuron.tp);
     }
 
+    #[test]
+    fn test_type_predicates() {
+        let contact = Neuron::new(0, 0, 0, 1, 1, 1, 0, 0);
+        let sensory = Neuron::new(1, 1, 1, 2, 2, 2, 1, 1);
+        let motor = Neuron::new(2, 2, 2, 3, 3, 3, 2, 1);
+
+        assert!(contact.is_contact() && !contact.is_sensory() && !contact.is_motor(), "({}, {}, {}) -> Expected only is_contact to be true", contact.x, contact.y, contact.z);
+        assert!(!sensory.is_contact() && sensory.is_sensory() && !sensory.is_motor(), "({}, {}, {}) -> Expected only is_sensory to be true", sensory.x, sensory.y, sensory.z);
+        assert!(!motor.is_contact() && !motor.is_sensory() && motor.is_motor(), "({}, {}, {}) -> Expected only is_motor to be true", motor.x, motor.y, motor.z);
+        assert!(contact.is_inhibitory() && !contact.is_excitatory(), "({}, {}, {}) -> Expected only is_inhibitory to be true", contact.x, contact.y, contact.z);
+        assert!(!sensory.is_inhibitory() && sensory.is_excitatory(), "({}, {}, {}) -> Expected only is_excitatory to be true", sensory.x, sensory.y, sensory.z);
+    }
+
     #[test]
     fn test_establish_axonal_connection() {
         let mut neuron1 = Neuron::new(1, 2, 3, 2, 3, 4, 0, 1);
         let mut neuron2 = Neuron::new(4, 5, 6, 5, 6, 7, 0, 1);
 
-        neuron1.establish_axonal_connection(&mut neuron2);
+        neuron1.establish_axonal_connection(&mut neuron2).unwrap();
         assert!(neuron1.ac.contains(&(4, 5, 6)), "Neuron1 should have an axonal connection to (4, 5, 6)");
         assert!(neuron2.dc.contains(&(1, 2, 3)), "Neuron2 should have a dendritic connection to (1, 2, 3)");
     }
@@ -30,7 +59,7 @@ mod tests {
         let mut neuron1 = Neuron::new(1, 2, 3, 2, 3, 4, 0, 1);
         let mut neuron2 = Neuron::new(4, 5, 6, 5, 6, 7, 0, 1);
         
-        neuron1.establish_axonal_connection(&mut neuron2);
+        neuron1.establish_axonal_connection(&mut neuron2).unwrap();
         neuron1.terminate_axonal_connection(&mut neuron2);
         assert!(!neuron1.ac.contains(&(4, 5, 6)), "Neuron1 should not have an axonal connection to (4, 5, 6)");
         assert!(!neuron2.dc.contains(&(1, 2, 3)), "Neuron2 should not have a dendritic connection to (1, 2, 3)");
@@ -41,7 +70,7 @@ mod tests {
         let mut neuron1 = Neuron::new(1, 2, 3, 2, 3, 4, 0, 1);
         let mut neuron2 = Neuron::new(4, 5, 6, 5, 6, 7, 0, 1);
 
-        neuron1.establish_dendritic_connection(&mut neuron2);
+        neuron1.establish_dendritic_connection(&mut neuron2).unwrap();
         assert!(neuron1.dc.contains(&(4, 5, 6)), "Neuron1 should have a dendritic connection to (4, 5, 6)");
         assert!(neuron2.ac.contains(&(1, 2, 3)), "Neuron2 should have an axonal connection to (1, 2, 3)");
     }
@@ -51,7 +80,7 @@ mod tests {
         let mut neuron1 = Neuron::new(1, 2, 3, 2, 3, 4, 0, 1);
         let mut neuron2 = Neuron::new(4, 5, 6, 5, 6, 7, 0, 1);
         
-        neuron1.establish_dendritic_connection(&mut neuron2);
+        neuron1.establish_dendritic_connection(&mut neuron2).unwrap();
         neuron1.terminate_dendritic_connection(&mut neuron2);
         assert!(!neuron1.dc.contains(&(4, 5, 6)), "Neuron1 should not have a dendritic connection to (4, 5, 6)");
         assert!(!neuron2.ac.contains(&(1, 2, 3)), "Neuron2 should not have an axonal connection to (1, 2, 3)");
@@ -65,7 +94,7 @@ mod tests {
         neuron1.sw = 0.05; 
         neuron1.sst = 0.05; 
         neuron2.sw = 0.1; 
-        neuron1.establish_axonal_connection(&mut neuron2);
+        neuron1.establish_axonal_connection(&mut neuron2).unwrap();
         neuron1.prune_axonal_connection(&mut neuron2);
 
         assert!(!neuron1.ac.contains(&(4, 5, 6)), "({}, {}, {}) -> Neuron1 should not have an axonal connection to (4, 5, 6) after pruning", neuron1.x, neuron1.y, neuron1.z);
@@ -80,7 +109,7 @@ mod tests {
         neuron1.sw = 0.05; 
         neuron2.sw = 0.1; 
         neuron2.sst = 0.1; 
-        neuron1.establish_axonal_connection(&mut neuron2); 
+        neuron1.establish_axonal_connection(&mut neuron2).unwrap(); 
         neuron2.prune_dendritic_connection(&mut neuron1);
         
         assert!(!neuron2.dc.contains(&(1, 1, 1)), "({}, {}, {}) -> Neuron2 should not have a dendritic connection to (1, 1, 1) after pruning", neuron2.x, neuron2.y, neuron2.z);
@@ -128,4 +157,449 @@ mod tests {
         assert!(neuron2.mp < neuron2.tp, "({}, {}, {}) -> Expected neuron2 mp < tp , got mp{}, tp{}", neuron1.x, neuron1.y, neuron1.z, neuron1.mp, neuron1.tp);
         assert!(neuron2.sw < 1.0, "({}, {}, {}) -> Expected neuron2 synaptic weight to decrease after signaling with reduced weight, got {}", neuron2.x, neuron2.y, neuron2.z, neuron1.sw);
     }
+
+    #[tokio::test]
+    async fn test_custom_plasticity_rule() {
+        let mut neuron1 = Neuron::new(1, 1, 1, 2, 2, 2, 1, 1);
+        let mut neuron2 = Neuron::new(1, 2, 3, 2, 3, 4, 1, 1);
+        neuron1.sw = 0.5;
+        neuron2.sw = 0.5;
+        neuron2.set_plasticity_rule(MultiplicativeRule);
+
+        neuron1.transmit(20.0, None).await;
+        neuron2.transmit(20.0, None).await;
+
+        let expected = 0.5 * (1.0 + (neuron2.ltp + neuron2.ltd) * neuron2.pr);
+        assert_eq!(neuron2.sw, expected, "({}, {}, {}) -> Expected multiplicative rule to set synaptic weight to {}, got {}", neuron2.x, neuron2.y, neuron2.z, expected, neuron2.sw);
+        assert!(neuron1.sw != neuron2.sw, "({}, {}, {}) -> Expected additive and multiplicative rules to diverge, got {} and {}", neuron1.x, neuron1.y, neuron1.z, neuron1.sw, neuron2.sw);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_with_random_state() {
+        let resting = Neuron::new(1, 1, 1, 2, 2, 2, 1, 1);
+        let mut neuron = resting.clone();
+        neuron.with_random_state(&mut SmallRng::seed_from_u64(5));
+
+        assert_eq!(neuron.validate(), Ok(()), "({}, {}, {}) -> Expected the randomized neuron to be valid", neuron.x, neuron.y, neuron.z);
+        let fields = |n: &Neuron| [n.mp, n.fr, n.ap, n.sw, n.sst, n.pr, n.ltp, n.ltd];
+        for (i, (value, default)) in fields(&neuron).into_iter().zip(fields(&resting)).enumerate() {
+            assert_ne!(value, default, "({}, {}, {}) -> Expected field {} to differ from the resting default", neuron.x, neuron.y, neuron.z, i);
+        }
+        assert_eq!(neuron.ap, neuron.mp - Neuron::RESTING_POTENTIAL, "({}, {}, {}) -> Expected the accumulated potential to match the membrane potential", neuron.x, neuron.y, neuron.z);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_mutate() {
+        let original = Neuron::new(1, 1, 1, 2, 2, 2, 1, 1);
+        let mut neuron = original.clone();
+        let mut rng = SmallRng::seed_from_u64(7);
+        for _ in 0..20 {
+            neuron.mutate(0.5, 0.3, &mut rng);
+            assert_eq!(neuron.validate(), Ok(()), "({}, {}, {}) -> Expected the mutated neuron to stay valid", neuron.x, neuron.y, neuron.z);
+        }
+
+        let changed = [
+            neuron.sw != original.sw, neuron.sst != original.sst, neuron.pr != original.pr,
+            neuron.ltp != original.ltp, neuron.ltd != original.ltd, neuron.nc != original.nc,
+        ];
+        assert!(changed.iter().filter(|&&c| c).count() >= 3, "({}, {}, {}) -> Expected several tunable fields to change, got {:?}", neuron.x, neuron.y, neuron.z, changed);
+        assert!((0.0..=Neuron::MAX_LTP).contains(&neuron.ltp) && (Neuron::MIN_LTD..=0.0).contains(&neuron.ltd), "({}, {}, {}) -> Expected ltp {} and ltd {} within bounds", neuron.x, neuron.y, neuron.z, neuron.ltp, neuron.ltd);
+        assert_eq!((neuron.mp, neuron.fr), (original.mp, original.fr), "({}, {}, {}) -> Expected the activity state to be untouched", neuron.x, neuron.y, neuron.z);
+
+        let mut unchanged = original.clone();
+        unchanged.mutate(0.0, 0.3, &mut rng);
+        assert_eq!(unchanged.plasticity_summary(), original.plasticity_summary(), "({}, {}, {}) -> Expected a zero rate to leave the neuron unchanged", unchanged.x, unchanged.y, unchanged.z);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_crossover() {
+        let mut a = Neuron::new(1, 1, 1, 2, 2, 2, 1, 1);
+        let mut b = Neuron::new(5, 5, 5, 6, 6, 6, 2, 1);
+        (a.sw, a.sst, a.pr, a.ltp, a.ltd, a.nc) = (0.9, 0.1, 0.2, 0.3, -0.4, 0.5);
+        (b.sw, b.sst, b.pr, b.ltp, b.ltd, b.nc) = (0.1, 0.6, 0.7, 0.8, -0.9, 1.5);
+        let mut target = Neuron::new(9, 9, 9, 9, 9, 8, 0, 1);
+        a.establish_axonal_connection(&mut target).unwrap();
+
+        let mut rng = SmallRng::seed_from_u64(11);
+        let mut from_a = [false; 6];
+        let mut from_b = [false; 6];
+        for _ in 0..20 {
+            let child = Neuron::crossover(&a, &b, &mut rng);
+            let fields = [(child.sw, a.sw, b.sw), (child.sst, a.sst, b.sst), (child.pr, a.pr, b.pr), (child.ltp, a.ltp, b.ltp), (child.ltd, a.ltd, b.ltd), (child.nc, a.nc, b.nc)];
+            for (i, (value, x, y)) in fields.into_iter().enumerate() {
+                assert!(value == x || value == y, "({}, {}, {}) -> Expected field {} to match a parent, got {}", child.x, child.y, child.z, i, value);
+                from_a[i] |= value == x;
+                from_b[i] |= value == y;
+            }
+            let parent = if (child.x, child.y, child.z) == (a.x, a.y, a.z) { &a } else { &b };
+            assert_eq!(((child.x, child.y, child.z), &child.ac), ((parent.x, parent.y, parent.z), &parent.ac), "({}, {}, {}) -> Expected position and connections from one parent", child.x, child.y, child.z);
+        }
+        assert!(from_a.iter().chain(&from_b).all(|&inherited| inherited), "Expected every field to be inherited from both parents across offspring");
+    }
+
+    #[cfg(feature = "rand")]
+    fn stochastic_firing_sequence(neuron: &mut Neuron, count: usize) -> Vec<bool> {
+        (0..count).map(|_| {
+            neuron.ap = 5.0;
+            neuron.mp = neuron.tp;
+            neuron.detect_stochastic() != 0.0
+        }).collect()
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_seeded_stochastic_firing() {
+        let mut neuron1 = Neuron::new(1, 1, 1, 2, 2, 2, 1, 1);
+        let mut neuron2 = Neuron::new(1, 2, 3, 2, 3, 4, 1, 1);
+        let mut neuron3 = Neuron::new(3, 2, 1, 4, 3, 2, 1, 1);
+        neuron1.set_seed(42);
+        neuron2.set_seed(42);
+        neuron3.set_seed(7);
+
+        let sequence1 = stochastic_firing_sequence(&mut neuron1, 64);
+        let sequence2 = stochastic_firing_sequence(&mut neuron2, 64);
+        let sequence3 = stochastic_firing_sequence(&mut neuron3, 64);
+        assert_eq!(sequence1, sequence2, "Expected neurons with the same seed to fire identically");
+        assert_ne!(sequence1, sequence3, "Expected neurons with different seeds to diverge");
+
+        neuron1.reset();
+        let replay = stochastic_firing_sequence(&mut neuron1, 64);
+        assert_eq!(sequence1, replay, "({}, {}, {}) -> Expected reset to reseed the original random stream", neuron1.x, neuron1.y, neuron1.z);
+    }
+
+    #[tokio::test]
+    async fn test_plasticity_summary() {
+        let mut neuron = Neuron::new(1, 1, 1, 2, 2, 2, 1, 1);
+        neuron.transmit(5.0, None).await;
+        neuron.transmit(-20.0, None).await;
+
+        let summary = neuron.plasticity_summary();
+        assert!(neuron.ltp > 0.0 && neuron.ltd < 0.0, "({}, {}, {}) -> Expected both potentiation and depression, got ltp {}, ltd {}", neuron.x, neuron.y, neuron.z, neuron.ltp, neuron.ltd);
+        assert_eq!((summary.ltp, summary.ltd, summary.pr, summary.sst, summary.sw), (neuron.ltp, neuron.ltd, neuron.pr, neuron.sst, neuron.sw), "({}, {}, {}) -> Expected summary to mirror the individual fields, got {:?}", neuron.x, neuron.y, neuron.z, summary);
+        assert_eq!(summary.net_potentiation, neuron.ltp + neuron.ltd, "({}, {}, {}) -> Expected net potentiation to be ltp + ltd, got {}", neuron.x, neuron.y, neuron.z, summary.net_potentiation);
+    }
+
+    #[tokio::test]
+    async fn test_plasticity_throttled_during_relative_refractory_period() {
+        let mut recovered = Neuron::new(1, 1, 1, 2, 2, 2, 1, 1);
+        let mut recovering = Neuron::new(1, 2, 3, 2, 3, 4, 1, 1);
+        recovered.sw = 0.5;
+        recovering.sw = 0.5;
+        recovering.rrp = 0.2 * Neuron::BASE_RELATIVE_REFRACTORY_PERIOD;
+
+        recovered.transmit(5.0, None).await;
+        recovering.transmit(5.0, None).await;
+
+        let full_change = recovered.sw - 0.5;
+        let throttled_change = recovering.sw - 0.5;
+        assert!(full_change > 0.0, "({}, {}, {}) -> Expected synaptic weight to increase, got change {}", recovered.x, recovered.y, recovered.z, full_change);
+        assert!((throttled_change - 0.2 * full_change).abs() < 1e-12, "({}, {}, {}) -> Expected weight change to be scaled by the recovery fraction, got {} versus {}", recovering.x, recovering.y, recovering.z, throttled_change, full_change);
+    }
+
+    #[test]
+    fn test_fire_probability() {
+        let mut neuron = Neuron::new(1, 1, 1, 2, 2, 2, 1, 1);
+        neuron.mp = neuron.tp;
+        let before = (neuron.mp, neuron.tp, neuron.ap);
+        assert!((neuron.fire_probability() - 0.5).abs() < 1e-12, "({}, {}, {}) -> Expected 0.5 at threshold, got {}", neuron.x, neuron.y, neuron.z, neuron.fire_probability());
+        assert_eq!((neuron.mp, neuron.tp, neuron.ap), before, "({}, {}, {}) -> Expected the neuron to be unchanged", neuron.x, neuron.y, neuron.z);
+
+        neuron.mp = neuron.tp + 20.0;
+        assert!(neuron.fire_probability() > 0.999, "({}, {}, {}) -> Expected near-certain firing well above threshold, got {}", neuron.x, neuron.y, neuron.z, neuron.fire_probability());
+        neuron.mp = neuron.tp - 20.0;
+        assert!(neuron.fire_probability() < 0.001, "({}, {}, {}) -> Expected near-zero probability well below threshold, got {}", neuron.x, neuron.y, neuron.z, neuron.fire_probability());
+
+        neuron.mp = neuron.tp + 1.0;
+        let shallow = neuron.fire_probability();
+        neuron.params.stochastic_gain = 4.0;
+        assert!(neuron.fire_probability() > shallow, "({}, {}, {}) -> Expected a higher gain to sharpen the probability, got {} against {}", neuron.x, neuron.y, neuron.z, neuron.fire_probability(), shallow);
+    }
+
+    #[tokio::test]
+    async fn test_distance_attenuation() {
+        let near = Neuron::new(1, 0, 0, 2, 0, 0, 1, 1);
+        let far = Neuron::new(3, 0, 0, 4, 0, 0, 1, 1);
+        let mut receiver = Neuron::new(0, 0, 0, 1, 0, 0, 1, 1);
+        receiver.params.distance_attenuation = DistanceAttenuation::InverseSquare;
+
+        let mut from_near = receiver.clone();
+        from_near.transmit(1.0, Some(&near)).await;
+        let mut from_far = receiver.clone();
+        from_far.transmit(1.0, Some(&far)).await;
+
+        let near_change = from_near.ap - receiver.ap;
+        let far_change = from_far.ap - receiver.ap;
+        assert!(far_change > 0.0 && near_change > far_change, "({}, {}, {}) -> Expected the near source to contribute more, got {} versus {}", receiver.x, receiver.y, receiver.z, near_change, far_change);
+        assert!((near_change / far_change - 9.0).abs() < 1e-9, "({}, {}, {}) -> Expected inverse-square scaling by distance 1 versus 3, got ratio {}", receiver.x, receiver.y, receiver.z, near_change / far_change);
+    }
+
+    #[tokio::test]
+    async fn test_coactivation_tracking() {
+        let mut neuron1 = Neuron::new(1, 1, 1, 2, 2, 2, 1, 1);
+        let mut neuron2 = Neuron::new(1, 2, 1, 2, 3, 2, 1, 1);
+        neuron1.establish_axonal_connection(&mut neuron2).unwrap();
+        neuron2.enable_coactivation_tracking();
+
+        for round in 1..=3 {
+            neuron1.reset();
+            neuron1.transmit(20.0, None).await;
+            assert!(neuron1.detect() > 0.0, "({}, {}, {}) -> Expected neuron1 to fire in round {}", neuron1.x, neuron1.y, neuron1.z, round);
+            neuron2.reset();
+            neuron2.transmit(30.0, Some(&neuron1)).await;
+            assert!(neuron2.detect() > 0.0, "({}, {}, {}) -> Expected neuron2 to fire in round {}", neuron2.x, neuron2.y, neuron2.z, round);
+            assert_eq!(neuron2.coactivation((1, 1, 1)), round, "({}, {}, {}) -> Expected coactivation count to rise with each co-firing", neuron2.x, neuron2.y, neuron2.z);
+        }
+        assert_eq!(neuron2.coactivation((9, 9, 9)), 0, "({}, {}, {}) -> Expected no coactivation with an unknown partner", neuron2.x, neuron2.y, neuron2.z);
+    }
+
+    #[tokio::test]
+    async fn test_firing_rate_smoothing_params() {
+        let mut fast = Neuron::new(1, 1, 1, 2, 2, 2, 1, 1);
+        let mut slow = Neuron::new(1, 2, 3, 2, 3, 4, 1, 1);
+        slow.params.firing_rate_decrease_factor = 0.98;
+        fast.fr = 0.5;
+        slow.fr = 0.5;
+
+        for _ in 0..10 {
+            fast.transmit(0.0, None).await;
+            slow.transmit(0.0, None).await;
+        }
+        assert!(slow.fr > fast.fr, "({}, {}, {}) -> Expected the slower-decaying neuron to retain a higher firing rate, got {} versus {}", slow.x, slow.y, slow.z, slow.fr, fast.fr);
+        assert!((fast.fr - 0.5 * Neuron::FIRING_RATE_DECREASE_FACTOR.powi(10)).abs() < 1e-12, "({}, {}, {}) -> Expected the default decay factor, got {}", fast.x, fast.y, fast.z, fast.fr);
+    }
+
+    #[test]
+    fn test_equilibrium_firing_rate() {
+        let neuron = Neuron::new(1, 1, 1, 2, 2, 2, 1, 1);
+        // Without leak any sustained excitation saturates the rate and any inhibition silences it
+        assert_eq!(neuron.equilibrium_firing_rate(0.5), Neuron::MAX_FIRING_RATE, "({}, {}, {}) -> Expected weak excitation to saturate without leak", neuron.x, neuron.y, neuron.z);
+        assert_eq!(neuron.equilibrium_firing_rate(-5.0), 0.0, "({}, {}, {}) -> Expected inhibition to silence the neuron", neuron.x, neuron.y, neuron.z);
+
+        // With leak 0.25, an input of 0.5 settles at ap = 0.08 * 0.5 / 0.25 = 0.16, far below threshold
+        let mut leaky = neuron.clone();
+        leaky.params.leak_rate = 0.25;
+        assert_eq!(leaky.equilibrium_firing_rate(0.5), 0.0, "({}, {}, {}) -> Expected the leak to hold the neuron below threshold", leaky.x, leaky.y, leaky.z);
+
+        // An input of 20 settling at ap = 0.8 * 20 / leak = 15.16 balances -70 + ap = -55 + 0.01 * ap + 0.02 * fr at fr = 0.42
+        leaky.params.leak_rate = 16.0 / 15.16;
+        let rate = leaky.equilibrium_firing_rate(20.0);
+        assert!((rate - 0.42).abs() < 1e-9, "({}, {}, {}) -> Expected the rate where the adapted threshold meets the membrane, got {}", leaky.x, leaky.y, leaky.z, rate);
+
+        // In conductance mode without leak the membrane reaches the excitatory reversal potential, above any threshold
+        let mut conductance = neuron.clone();
+        conductance.params.conductance_based = true;
+        assert_eq!(conductance.equilibrium_firing_rate(5.0), Neuron::MAX_FIRING_RATE, "({}, {}, {}) -> Expected saturation at the reversal potential", conductance.x, conductance.y, conductance.z);
+
+        // A rate that is neither boosted nor decayed stays where it is
+        let mut frozen_rate = leaky.clone();
+        frozen_rate.fr = 0.3;
+        frozen_rate.params.firing_rate_decrease_factor = 1.0;
+        assert_eq!(frozen_rate.equilibrium_firing_rate(0.5), 0.3, "({}, {}, {}) -> Expected the rate to stay without decay", frozen_rate.x, frozen_rate.y, frozen_rate.z);
+    }
+
+    #[test]
+    fn test_rate_to_input() {
+        let mut neuron = Neuron::new(1, 1, 1, 2, 2, 2, 1, 1);
+        neuron.params.leak_rate = 1.0;
+        let mut previous = 0.0;
+        for rate in [0.1, 0.3, 0.55, 0.65, 0.9] {
+            let input = neuron.rate_to_input(rate);
+            let reached = neuron.equilibrium_firing_rate(input);
+            assert!((reached - rate).abs() < 1e-6, "({}, {}, {}) -> Expected input {} to sustain a firing rate of {}, got {}", neuron.x, neuron.y, neuron.z, input, rate, reached);
+            assert!(input > previous, "({}, {}, {}) -> Expected rate {} to need more input than the previous rate, got {} after {}", neuron.x, neuron.y, neuron.z, rate, input, previous);
+            previous = input;
+        }
+
+        // Without leak any excitation saturates the rate, so every intermediate rate maps to the smallest input
+        let tight = Neuron::new(1, 1, 1, 2, 2, 2, 1, 1);
+        let input = tight.rate_to_input(0.5);
+        assert!(input < 1e-9 && tight.equilibrium_firing_rate(input) == Neuron::MAX_FIRING_RATE, "({}, {}, {}) -> Expected the smallest saturating input, got {}", tight.x, tight.y, tight.z, input);
+    }
+
+    #[test]
+    fn test_threshold_adaptation_toggle() {
+        let mut adapting = Neuron::new(1, 1, 1, 2, 2, 2, 1, 1);
+        let mut fixed = Neuron::new(1, 2, 3, 2, 3, 4, 1, 1);
+        adapting.params.threshold_potential_boost_factor_for_firing_rate = 2.0;
+        fixed.params.threshold_adaptation = false;
+
+        for _ in 0..200 {
+            adapting.transmit_sync(20.0);
+            adapting.detect();
+            fixed.transmit_sync(20.0);
+            fixed.detect();
+        }
+        assert!(adapting.tp > Neuron::MIN_THRESHOLD_POTENTIAL, "({}, {}, {}) -> Expected the adapting threshold to rise, got {}", adapting.x, adapting.y, adapting.z, adapting.tp);
+        assert_eq!(fixed.tp, Neuron::MIN_THRESHOLD_POTENTIAL, "({}, {}, {}) -> Expected the non-adapting threshold to stay fixed, got {}", fixed.x, fixed.y, fixed.z, fixed.tp);
+        assert!(fixed.fr > 0.0, "({}, {}, {}) -> Expected the non-adapting neuron to have fired", fixed.x, fixed.y, fixed.z);
+    }
+
+    #[test]
+    fn test_response_curve() {
+        let excitatory = Neuron::new(1, 1, 1, 2, 2, 2, 1, 1);
+        let inhibitory = Neuron::new(1, 2, 3, 2, 3, 4, 1, 0);
+        let inputs = [1.0, 5.0, 10.0, 20.0, 30.0];
+
+        let curve = excitatory.response_curve(&inputs);
+        assert_eq!(&curve[..3], &[0.0, 0.0, 0.0], "({}, {}, {}) -> Expected no output below threshold, got {:?}", excitatory.x, excitatory.y, excitatory.z, curve);
+        assert!(curve[3..].iter().all(|&output| output > 0.0), "({}, {}, {}) -> Expected positive output above threshold, got {:?}", excitatory.x, excitatory.y, excitatory.z, curve);
+        let curve = inhibitory.response_curve(&inputs);
+        assert!(curve[3..].iter().all(|&output| output < 0.0), "({}, {}, {}) -> Expected negative output above threshold, got {:?}", inhibitory.x, inhibitory.y, inhibitory.z, curve);
+
+        assert_eq!((excitatory.ap, excitatory.mp, excitatory.fr), (0.0, Neuron::RESTING_POTENTIAL, 0.0), "({}, {}, {}) -> Expected the original neuron to be untouched", excitatory.x, excitatory.y, excitatory.z);
+        assert_eq!(excitatory.plasticity_summary(), Neuron::new(1, 1, 1, 2, 2, 2, 1, 1).plasticity_summary(), "({}, {}, {}) -> Expected the original plasticity state to be untouched", excitatory.x, excitatory.y, excitatory.z);
+    }
+
+    #[test]
+    fn test_cumulative_threshold_adaptation() {
+        let mut neuron = Neuron::new(1, 1, 1, 2, 2, 2, 1, 1);
+        neuron.params.cumulative_threshold_adaptation = true;
+
+        // Sustained input: the threshold after each fire should exceed the threshold after the previous one
+        let mut fired_thresholds = Vec::new();
+        for _ in 0..200 {
+            neuron.transmit_sync(20.0);
+            if neuron.detect() != 0.0 {
+                fired_thresholds.push(neuron.tp);
+            }
+        }
+        assert!(fired_thresholds.len() >= 3, "({}, {}, {}) -> Expected sustained firing, got {:?}", neuron.x, neuron.y, neuron.z, fired_thresholds);
+        assert!(fired_thresholds.windows(2).all(|w| w[1] > w[0]), "({}, {}, {}) -> Expected the threshold to ratchet upward, got {:?}", neuron.x, neuron.y, neuron.z, fired_thresholds);
+
+        // Idle period: inputs alone leave the adaptation in place, idle ticks decay it and the threshold relaxes
+        while neuron.arp > 0.0 {
+            neuron.transmit_sync(0.0);
+        }
+        let (adapted_tp, adapted_ta) = (neuron.tp, neuron.ta);
+        neuron.transmit_sync(0.0);
+        assert_eq!(neuron.ta, adapted_ta, "({}, {}, {}) -> Expected the adaptation to decay per tick, not per input", neuron.x, neuron.y, neuron.z);
+        for _ in 0..100 {
+            neuron.relax_threshold();
+        }
+        assert!(neuron.ta < adapted_ta && neuron.tp < adapted_tp, "({}, {}, {}) -> Expected the threshold to relax while idle, got {} -> {}", neuron.x, neuron.y, neuron.z, adapted_tp, neuron.tp);
+
+        let mut fixed = Neuron::new(1, 1, 1, 2, 2, 2, 1, 1);
+        fixed.transmit_sync(20.0);
+        fixed.detect();
+        assert_eq!(fixed.ta, 0.0, "({}, {}, {}) -> Expected no cumulative adaptation unless enabled", fixed.x, fixed.y, fixed.z);
+    }
+
+    #[tokio::test]
+    async fn test_transmit_with_timeout() {
+        let source = Neuron::new(1000, 1000, 1000, 999, 999, 999, 0, 1);
+        let mut target = Neuron::new(0, 0, 0, 1, 1, 1, 0, 1);
+
+        let result = target.transmit_with_timeout(20.0, Some(&source), Duration::from_millis(1)).await;
+        assert!(matches!(result, Err(TransmitError::DelayTimeout { .. })), "({}, {}, {}) -> Expected the far-away signal to time out, got {:?}", target.x, target.y, target.z, result);
+        assert_eq!((target.ap, target.mp), (0.0, Neuron::RESTING_POTENTIAL), "({}, {}, {}) -> Expected the timed-out signal to be dropped", target.x, target.y, target.z);
+
+        let result = target.transmit_with_timeout(20.0, None, Duration::from_millis(1)).await;
+        assert_eq!(result, Ok(()), "({}, {}, {}) -> Expected an undelayed signal to be applied, got {:?}", target.x, target.y, target.z, result);
+        assert!(target.ap > 0.0, "({}, {}, {}) -> Expected the applied signal to accumulate potential", target.x, target.y, target.z);
+    }
+
+    #[tokio::test]
+    async fn test_min_synaptic_delay() {
+        let source = Neuron::new(3, 3, 3, 3, 3, 3, 0, 1);
+        let mut target = Neuron::new(3, 3, 3, 4, 4, 4, 0, 1);
+        target.params.min_synaptic_delay = 5.0;
+
+        let timeout = Duration::from_millis(1);
+        let result = target.transmit_with_timeout(20.0, Some(&source), timeout).await;
+        assert_eq!(result, Err(TransmitError::DelayTimeout { delay: Duration::from_millis(5), timeout }), "({}, {}, {}) -> Expected the co-located signal to incur the minimum delay, got {:?}", target.x, target.y, target.z, result);
+
+        target.params.min_synaptic_delay = 0.0;
+        let result = target.transmit_with_timeout(20.0, Some(&source), timeout).await;
+        assert_eq!(result, Ok(()), "({}, {}, {}) -> Expected no delay without a minimum, got {:?}", target.x, target.y, target.z, result);
+    }
+
+    #[test]
+    fn test_synaptic_scaling() {
+        let mut neuron = Neuron::new(1, 1, 1, 2, 2, 2, 1, 1);
+        neuron.sw = 0.5;
+
+        neuron.fr = 0.8;
+        neuron.synaptic_scaling(0.2, 0.5);
+        assert!(neuron.sw < 0.5, "({}, {}, {}) -> Expected scaling above the target rate to reduce sw, got {}", neuron.x, neuron.y, neuron.z, neuron.sw);
+
+        let scaled = neuron.sw;
+        neuron.fr = 0.0;
+        neuron.synaptic_scaling(0.2, 0.5);
+        assert!(neuron.sw > scaled, "({}, {}, {}) -> Expected scaling below the target rate to increase sw, got {}", neuron.x, neuron.y, neuron.z, neuron.sw);
+
+        neuron.synaptic_scaling(100.0, 1.0);
+        assert_eq!(neuron.sw, Neuron::MAX_LTP, "({}, {}, {}) -> Expected sw to be clamped to its bound, got {}", neuron.x, neuron.y, neuron.z, neuron.sw);
+    }
+
+    #[test]
+    fn test_receptor_desensitization() {
+        let mut control = Neuron::new(1, 1, 1, 2, 2, 2, 1, 1);
+        control.params.receptor_desensitization_factor = 0.0;
+        let mut neuron = Neuron::new(1, 2, 3, 2, 3, 4, 1, 1);
+        neuron.params.receptor_desensitization_factor = 0.2;
+
+        let mut fires = 0;
+        for _ in 0..10 {
+            control.transmit_sync(30.0);
+            control.detect();
+            neuron.transmit_sync(30.0);
+            if neuron.detect() != 0.0 {
+                fires += 1;
+            }
+        }
+        assert!(fires > 1, "({}, {}, {}) -> Expected repeated firing, got {} fires", neuron.x, neuron.y, neuron.z, fires);
+        assert!(neuron.receptor_sensitivity < 1.0, "({}, {}, {}) -> Expected firing to desensitize the receptors, got {}", neuron.x, neuron.y, neuron.z, neuron.receptor_sensitivity);
+
+        // Leave the refractory periods so only the receptor gain differs
+        for n in [&mut control, &mut neuron] {
+            (n.arp, n.rrp) = (0.0, Neuron::BASE_RELATIVE_REFRACTORY_PERIOD);
+        }
+        let (control_ap, neuron_ap) = (control.ap, neuron.ap);
+        control.transmit_sync(30.0);
+        neuron.transmit_sync(30.0);
+        assert!(neuron.ap - neuron_ap < control.ap - control_ap, "({}, {}, {}) -> Expected a smaller ap change after desensitization, got {} vs {}", neuron.x, neuron.y, neuron.z, neuron.ap - neuron_ap, control.ap - control_ap);
+
+        let desensitized = neuron.receptor_sensitivity;
+        for _ in 0..20 {
+            neuron.recover_receptors();
+        }
+        assert!(neuron.receptor_sensitivity > desensitized, "({}, {}, {}) -> Expected sensitivity to recover while idle, got {}", neuron.x, neuron.y, neuron.z, neuron.receptor_sensitivity);
+        assert!(neuron.receptor_sensitivity <= Neuron::MAX_RECEPTOR_SENSITIVITY, "({}, {}, {}) -> Expected sensitivity not to exceed its maximum", neuron.x, neuron.y, neuron.z);
+    }
+
+    #[test]
+    fn test_default_receptor_desensitization() {
+        let mut neuron = Neuron::new(1, 1, 1, 2, 2, 2, 1, 1);
+        neuron.transmit_sync(30.0);
+        assert!(neuron.detect() != 0.0, "({}, {}, {}) -> Expected the neuron to fire", neuron.x, neuron.y, neuron.z);
+        let expected = Neuron::MAX_RECEPTOR_SENSITIVITY * (1.0 - Neuron::RECEPTOR_DESENSITIZATION_FACTOR);
+        assert!(expected < Neuron::MAX_RECEPTOR_SENSITIVITY, "({}, {}, {}) -> Expected desensitization to be on by default", neuron.x, neuron.y, neuron.z);
+        assert_eq!(neuron.receptor_sensitivity, expected, "({}, {}, {}) -> Expected a fire to desensitize the receptors with default params", neuron.x, neuron.y, neuron.z);
+
+        // A detection that does not fire is an idle tick
+        let mut idle = Neuron::new(1, 1, 1, 2, 2, 2, 1, 1);
+        idle.receptor_sensitivity = expected;
+        assert_eq!(idle.detect(), 0.0, "({}, {}, {}) -> Expected a resting neuron not to fire", idle.x, idle.y, idle.z);
+        assert!(idle.receptor_sensitivity > expected, "({}, {}, {}) -> Expected the receptors to recover on an idle detection, got {}", idle.x, idle.y, idle.z, idle.receptor_sensitivity);
+    }
+
+    #[test]
+    fn test_firing_state() {
+        let mut neuron = Neuron::new(1, 1, 1, 2, 2, 2, 1, 1);
+        assert_eq!(neuron.threshold_distance(), Neuron::MIN_THRESHOLD_POTENTIAL - Neuron::RESTING_POTENTIAL, "({}, {}, {}) -> Expected the resting distance to threshold", neuron.x, neuron.y, neuron.z);
+        assert_eq!(neuron.firing_state(), FiringState::Quiescent, "({}, {}, {}) -> Expected a resting neuron to be quiescent", neuron.x, neuron.y, neuron.z);
+
+        neuron.transmit_sync(15.0);
+        assert_eq!(neuron.firing_state(), FiringState::NearThreshold, "({}, {}, {}) -> Expected a distance of {} to be near threshold", neuron.x, neuron.y, neuron.z, neuron.threshold_distance());
+
+        neuron.transmit_sync(30.0);
+        assert!(neuron.threshold_distance() <= 0.0, "({}, {}, {}) -> Expected the neuron to reach threshold, got distance {}", neuron.x, neuron.y, neuron.z, neuron.threshold_distance());
+        assert_eq!(neuron.firing_state(), FiringState::Firing, "({}, {}, {}) -> Expected a neuron at threshold to be firing", neuron.x, neuron.y, neuron.z);
+
+        assert!(neuron.detect() > 0.0, "({}, {}, {}) -> Expected the neuron to fire", neuron.x, neuron.y, neuron.z);
+        assert_eq!(neuron.firing_state(), FiringState::Refractory, "({}, {}, {}) -> Expected a neuron that just fired to be refractory", neuron.x, neuron.y, neuron.z);
+    }
 }
@@ -0,0 +1,182 @@
+// tests/test_persistence.rs
+#![cfg(feature = "std")]
+use neuron::{ExternalPort, JsonFormat, Network, Neuron, NeuronParams, PersistenceError};
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+// Returns a path in the system temporary directory unique to this test process.
+fn temp_path(name: &str) -> PathBuf {
+    env::temp_dir().join(format!("neuron_{}_{}.json", std::process::id(), name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_region() {
+        let mut network = Network::new();
+        let ids: Vec<_> = (0..4).map(|x| network.add_neuron(Neuron::new(x, 0, 0, x, 0, 1, 0, 1))).collect();
+        for pair in ids.windows(2) {
+            network.connect(pair[0], pair[1]).unwrap();
+        }
+
+        let path = temp_path("save_region");
+        network.save_region(((1, 0, 0), (2, 0, 0)), &path, JsonFormat::Pretty).unwrap();
+        let (region, ports) = Network::load_region(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        let positions: Vec<_> = region.neurons().iter().map(|n| (n.x, n.y, n.z)).collect();
+        assert_eq!(positions, vec![(1, 0, 0), (2, 0, 0)], "Expected only in-bounds neurons to be persisted, got {:?}", positions);
+
+        let first = region.neuron(0);
+        let second = region.neuron(1);
+        assert!(first.dc.is_empty() && first.ac.contains(&(2, 0, 0)), "({}, {}, {}) -> Expected the incoming connection from (0, 0, 0) to be clipped, got ac {:?}, dc {:?}", first.x, first.y, first.z, first.ac, first.dc);
+        assert!(second.ac.is_empty() && second.dc.contains(&(1, 0, 0)), "({}, {}, {}) -> Expected the outgoing connection to (3, 0, 0) to be clipped, got ac {:?}, dc {:?}", second.x, second.y, second.z, second.ac, second.dc);
+
+        let expected_ports = [
+            ExternalPort { neuron: (1, 0, 0), remote: (0, 0, 0), outgoing: false },
+            ExternalPort { neuron: (2, 0, 0), remote: (3, 0, 0), outgoing: true },
+        ];
+        assert_eq!(ports.len(), 2, "Expected two external ports, got {:?}", ports);
+        assert!(expected_ports.iter().all(|port| ports.contains(port)), "Expected clipped connections to become external ports, got {:?}", ports);
+    }
+
+    #[test]
+    fn test_save_region_formats() {
+        let mut network = Network::new();
+        let ids: Vec<_> = (0..6).map(|x| network.add_neuron(Neuron::new(x, 0, 0, x, 0, 1, 0, 1))).collect();
+        for pair in ids.windows(2) {
+            network.connect(pair[0], pair[1]).unwrap();
+        }
+        network.neuron_mut(ids[2]).sw = 0.42;
+
+        let bounds = ((0, 0, 0), (5, 0, 0));
+        let pretty_path = temp_path("format_pretty");
+        let compact_path = temp_path("format_compact");
+        network.save_region(bounds, &pretty_path, JsonFormat::Pretty).unwrap();
+        network.save_region(bounds, &compact_path, JsonFormat::Compact).unwrap();
+        let pretty_size = fs::metadata(&pretty_path).unwrap().len();
+        let compact_size = fs::metadata(&compact_path).unwrap().len();
+        let (pretty, _) = Network::load_region(&pretty_path).unwrap();
+        let (compact, _) = Network::load_region(&compact_path).unwrap();
+        fs::remove_file(&pretty_path).unwrap();
+        fs::remove_file(&compact_path).unwrap();
+
+        assert!(compact_size < pretty_size, "Expected the compact file to be smaller, got {} against {} bytes", compact_size, pretty_size);
+        assert_eq!(pretty.len(), compact.len(), "Expected both formats to load the same number of neurons");
+        // Connection sets are compared directly, since their serialized order is unspecified.
+        let scalars = |neuron: &Neuron| {
+            let mut value = serde_json::to_value(neuron).unwrap();
+            value["ac"].take();
+            value["dc"].take();
+            value
+        };
+        for (a, b) in pretty.neurons().iter().zip(compact.neurons()) {
+            assert_eq!(scalars(a), scalars(b), "({}, {}, {}) -> Expected both formats to load identical fields", a.x, a.y, a.z);
+            assert_eq!((&a.ac, &a.dc), (&b.ac, &b.dc), "({}, {}, {}) -> Expected both formats to load identical connections", a.x, a.y, a.z);
+        }
+    }
+
+    #[test]
+    fn test_versioned_checksum() {
+        let mut network = Network::new();
+        let ids: Vec<_> = (0..3).map(|x| network.add_neuron(Neuron::new(x, 0, 0, x, 0, 1, 0, 1))).collect();
+        network.connect(ids[0], ids[1]).unwrap();
+        network.connect(ids[1], ids[2]).unwrap();
+
+        for format in [JsonFormat::Pretty, JsonFormat::Compact] {
+            let path = temp_path("versioned_checksum");
+            network.save_versioned(&path, format).unwrap();
+            let loaded = Network::load_versioned(&path).unwrap();
+            assert_eq!(loaded.to_adjacency_matrix(), network.to_adjacency_matrix(), "Expected an intact {:?} file to load unchanged", format);
+
+            // Alter a digit of the neuron data, keeping the JSON well-formed
+            let mut bytes = fs::read(&path).unwrap();
+            let mut field = bytes.windows(5).position(|window| window == b"\"sw\":").unwrap() + 5;
+            while bytes[field] == b' ' {
+                field += 1;
+            }
+            bytes[field] = if bytes[field] == b'9' { b'8' } else { bytes[field] + 1 };
+            fs::write(&path, &bytes).unwrap();
+            let result = Network::load_versioned(&path);
+            fs::remove_file(&path).unwrap();
+
+            assert!(matches!(result, Err(PersistenceError::ChecksumMismatch)), "Expected a checksum mismatch in a {:?} file, got {:?}", format, result.map(|network| network.len()));
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_versioned_random_state() {
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        for seed in 0..20 {
+            let mut rng = SmallRng::seed_from_u64(seed);
+            let mut network = Network::new();
+            for x in 0..4 {
+                let mut neuron = Neuron::new(x, 0, 0, x, 0, 1, 0, 1);
+                neuron.with_random_state(&mut rng);
+                network.add_neuron(neuron);
+            }
+
+            for format in [JsonFormat::Pretty, JsonFormat::Compact] {
+                let path = temp_path("versioned_random_state");
+                network.save_versioned(&path, format).unwrap();
+                let loaded = Network::load_versioned(&path);
+                fs::remove_file(&path).unwrap();
+
+                let loaded = loaded.unwrap_or_else(|error| panic!("Expected seed {} to load from a {:?} file, got {}", seed, format, error));
+                let fields = |network: &Network| network.neurons().iter().map(|n| [n.mp, n.fr, n.sw, n.sst, n.pr, n.ltp, n.ltd]).collect::<Vec<_>>();
+                assert_eq!(fields(&loaded), fields(&network), "Expected seed {} to load bit-identical state from a {:?} file", seed, format);
+            }
+        }
+    }
+
+    #[test]
+    fn test_persistence_errors() {
+        let missing = env::temp_dir().join(format!("neuron_{}_missing", std::process::id())).join("network.json");
+        let result = Network::load_region(&missing);
+        assert!(matches!(result, Err(PersistenceError::Io(_))), "Expected an io error for a bad path, got {:?}", result.map(|(network, _)| network.len()));
+        let result = Network::new().save_versioned(&missing, JsonFormat::Compact);
+        assert!(matches!(result, Err(PersistenceError::Io(_))), "Expected an io error when writing to a bad path, got {:?}", result);
+
+        let path = temp_path("persistence_errors");
+        fs::write(&path, "{ not json").unwrap();
+        let malformed = Network::load_versioned(&path);
+        fs::write(&path, r#"{"version":99,"checksum":0,"neurons":[]}"#).unwrap();
+        let future = Network::load_versioned(&path);
+        Network::new().save_versioned(&path, JsonFormat::Compact).unwrap();
+        let contents = fs::read_to_string(&path).unwrap().replace("[]", "[ ]"); // Whitespace is outside the checksummed form
+        fs::write(&path, contents).unwrap();
+        let reformatted = Network::load_versioned(&path);
+        let contents = fs::read_to_string(&path).unwrap().replace("[ ]", "[{}]"); // Still valid JSON, but different data
+        fs::write(&path, contents).unwrap();
+        let corrupted = Network::load_versioned(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert!(matches!(malformed, Err(PersistenceError::Serde(_))), "Expected a serde error for malformed JSON, got {:?}", malformed.map(|network| network.len()));
+        assert!(matches!(future, Err(PersistenceError::UnsupportedVersion(99))), "Expected an unsupported version error, got {:?}", future.map(|network| network.len()));
+        assert!(reformatted.is_ok_and(|network| network.is_empty()), "Expected a reformatted file to still verify");
+        assert!(matches!(corrupted, Err(PersistenceError::ChecksumMismatch)), "Expected a checksum mismatch, got {:?}", corrupted.map(|network| network.len()));
+        assert_eq!(PersistenceError::UnsupportedVersion(99).to_string(), "99 : unsupported format version", "Expected the version in the error message");
+    }
+
+    #[test]
+    fn test_deserialize_legacy_neuron() {
+        let legacy = r#"{
+            "x": 1, "y": 2, "z": 3, "ax": 2, "ay": 3, "az": 4, "nt": 0, "nrt": 1,
+            "acv": 1.0, "ap": 2.5, "tp": -55.0, "mp": -67.5, "fr": 0.1,
+            "sw": 0.75, "sst": 0.0, "pr": 1.0, "arp": 0.0, "rrp": 1.0,
+            "ac": [[4, 5, 6]], "dc": [], "nc": 1.0, "ltp": 0.01, "ltd": 0.0
+        }"#;
+
+        let neuron: Neuron = serde_json::from_str(legacy).unwrap();
+        assert_eq!((neuron.x, neuron.y, neuron.z, neuron.ap, neuron.sw), (1, 2, 3, 2.5, 0.75), "({}, {}, {}) -> Expected present fields to load", neuron.x, neuron.y, neuron.z);
+        assert!(neuron.ac.contains(&(4, 5, 6)), "({}, {}, {}) -> Expected connections to load", neuron.x, neuron.y, neuron.z);
+        assert_eq!(neuron.params, NeuronParams::default(), "({}, {}, {}) -> Expected default params, got {:?}", neuron.x, neuron.y, neuron.z, neuron.params);
+        assert!(neuron.plasticity_enabled, "({}, {}, {}) -> Expected plasticity to default to enabled", neuron.x, neuron.y, neuron.z);
+        assert_eq!(neuron.seed, None, "({}, {}, {}) -> Expected no seed by default", neuron.x, neuron.y, neuron.z);
+    }
+}
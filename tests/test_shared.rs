@@ -0,0 +1,60 @@
+// tests/test_shared.rs
+#![cfg(feature = "std")]
+use neuron::{Network, Neuron, SharedNetwork};
+use std::thread;
+
+// Fails to compile unless the type can be moved and shared across threads.
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_send_sync() {
+        assert_send_sync::<Neuron>();
+        assert_send_sync::<Network>();
+        assert_send_sync::<SharedNetwork>();
+    }
+
+    #[test]
+    fn test_shared_network_threads() {
+        let mut network = Network::new();
+        let ids: Vec<_> = (0..4).map(|x| network.add_neuron(Neuron::new(x, 0, 0, x, 0, 1, 0, 1))).collect();
+        for pair in ids.windows(2) {
+            network.connect(pair[0], pair[1]).unwrap();
+        }
+        let shared = SharedNetwork::new(network);
+
+        let writer = {
+            let shared = shared.clone();
+            thread::spawn(move || {
+                for _ in 0..50 {
+                    shared.write(|network| network.inject_current(20.0, Some(&[0])));
+                    shared.tick();
+                }
+            })
+        };
+        let reader = {
+            let shared = shared.clone();
+            thread::spawn(move || {
+                let mut clocks = Vec::new();
+                for _ in 0..50 {
+                    let (clock, edges) = shared.read(|network| (network.clock(), (0..network.len()).map(|id| network.targets(id).len()).sum::<usize>()));
+                    assert_eq!(edges, 3, "Expected readers never to observe a partially updated network");
+                    clocks.push(clock);
+                }
+                clocks
+            })
+        };
+
+        writer.join().unwrap();
+        let clocks = reader.join().unwrap();
+        assert!(clocks.windows(2).all(|w| w[0] <= w[1]), "Expected readers to observe a monotonic clock, got {:?}", clocks);
+
+        let network = shared.try_into_inner().expect("Expected the last handle to unwrap the network");
+        assert_eq!(network.clock(), 50, "Expected every writer tick to be applied");
+        assert_eq!(network.metrics().ticks, 50, "Expected the metrics to count every tick");
+        assert!(network.neuron(0).fire_count > 0, "Expected the driven neuron to have fired");
+    }
+}